@@ -1,8 +1,12 @@
+use convert_case::{Case, Casing};
 use proc_macro2::{Ident, Span};
 use syn::{
     parenthesized,
     parse::{ParseBuffer, ParseStream},
-    parse2, Attribute, DeriveInput, Expr, ExprPath, GenericParam, LitStr, Token, WherePredicate,
+    parse2, parse_quote,
+    spanned::Spanned,
+    Attribute, DeriveInput, Expr, ExprPath, GenericParam, Lit, LitStr, Meta, NestedMeta, Token,
+    WherePredicate,
 };
 
 // pub struct MapFieldAttribute {
@@ -26,6 +30,11 @@ pub struct FieldAttributesInfo {
     pub map: Option<syn::ExprPath>,
     /// Whether an additional where clause should be added to deserialize this field
     pub needs_predicate: bool,
+    /// Whether the field should be ignored, in which case it is never serialized and
+    /// it is always deserialized to its default value.
+    pub skip: bool,
+    /// Other keys that are accepted in addition to the name of the field.
+    pub alias: Vec<LitStr>,
 
     /// Span of the `default` attribute, if any, for compile error reporting purposes
     default_span: Option<Span>,
@@ -105,9 +114,27 @@ impl FieldAttributesInfo {
             self.map = Some(map)
         }
         self.needs_predicate |= other.needs_predicate;
+        self.skip |= other.skip;
+        self.alias.extend(other.alias);
 
         Ok(())
     }
+
+    /// Uses the attributes read from `#[serde(...)]` for the attributes that are not
+    /// given by `self`.
+    fn fill_from_serde(&mut self, serde: Self) {
+        if self.rename.is_none() {
+            self.rename = serde.rename;
+        }
+        if self.default.is_none() {
+            self.default = serde.default;
+            self.default_span = serde.default_span;
+        }
+        if self.alias.is_empty() {
+            self.alias = serde.alias;
+        }
+        self.skip |= serde.skip;
+    }
 }
 fn parse_rename(input: &ParseBuffer) -> Result<LitStr, syn::Error> {
     let _eq = input.parse::<Token![=]>()?;
@@ -152,6 +179,10 @@ impl syn::parse::Parse for FieldAttributesInfo {
                     this.missing_field_error = Some(expr);
                 }
                 "needs_predicate" => this.needs_predicate = true,
+                "skip" => this.skip = true,
+                "alias" => {
+                    this.alias.push(parse_rename(&input)?);
+                }
                 "error" => {
                     let _eq = input.parse::<Token![=]>()?;
                     let err_ty = input.parse::<syn::Type>()?;
@@ -188,8 +219,11 @@ impl syn::parse::Parse for FieldAttributesInfo {
 
 /// Parses an array of `syn::Attribute` into a single `FieldAttributesInfo` containing the
 /// relevant information for the generation of the code deserialising each field.
+///
+/// If `serde_compat` is true, the `#[serde(...)]` attributes are also read.
 pub fn read_jayson_field_attributes(
     attributes: &[Attribute],
+    serde_compat: bool,
 ) -> Result<FieldAttributesInfo, syn::Error> {
     let mut this = FieldAttributesInfo::default();
     for attribute in attributes {
@@ -203,17 +237,149 @@ pub fn read_jayson_field_attributes(
             continue;
         }
     }
+    if serde_compat {
+        this.fill_from_serde(read_serde_field_attributes(attributes)?);
+    }
     Ok(this)
 }
 
-/// The value of the `default` field attribute
+/// The value of the `rename_all` attribute
 #[derive(Debug, Clone)]
-pub enum RenameAll {
-    /// `#[jayson(rename_all = camelCase)]`
-    CamelCase,
+pub struct RenameAll {
+    pub rule: RenameRule,
+    /// Whether the attribute was read from `#[serde(...)]`, in which case the names are
+    /// converted exactly as serde does
+    pub serde: bool,
+}
+
+impl RenameAll {
+    /// Applies the rule to the name of a field
+    pub fn apply_to_field(&self, field: &str) -> String {
+        if self.serde {
+            self.rule.apply_to_field(field)
+        } else {
+            self.rule.apply_to_ident(field)
+        }
+    }
+
+    /// Applies the rule to the name of a variant
+    pub fn apply_to_variant(&self, variant: &str) -> String {
+        if self.serde {
+            self.rule.apply_to_variant(variant)
+        } else {
+            self.rule.apply_to_ident(variant)
+        }
+    }
+}
+
+/// A case conversion rule of the `rename_all` attribute
+#[derive(Debug, Clone)]
+pub enum RenameRule {
     /// `#[jayson(rename_all = lowercase)]`
-    LowerCase,
+    Lower,
+    /// `#[jayson(rename_all = UPPERCASE)]`
+    Upper,
+    /// `#[jayson(rename_all = PascalCase)]`
+    Pascal,
+    /// `#[jayson(rename_all = camelCase)]`
+    Camel,
+    /// `#[jayson(rename_all = snake_case)]`
+    Snake,
+    /// `#[jayson(rename_all = SCREAMING_SNAKE_CASE)]`
+    ScreamingSnake,
+    /// `#[jayson(rename_all = "kebab-case")]`
+    Kebab,
+    /// `#[jayson(rename_all = "SCREAMING-KEBAB-CASE")]`
+    ScreamingKebab,
 }
+
+impl RenameRule {
+    /// Applies the rule to the name of a field or variant, as done by the
+    /// `#[jayson(rename_all = ...)]` attribute.
+    fn apply_to_ident(&self, ident: &str) -> String {
+        match self {
+            RenameRule::Lower => ident.to_lowercase(),
+            RenameRule::Upper => ident.to_uppercase(),
+            RenameRule::Pascal => ident.to_case(Case::Pascal),
+            RenameRule::Camel => ident.to_case(Case::Camel),
+            RenameRule::Snake => ident.to_case(Case::Snake),
+            RenameRule::ScreamingSnake => ident.to_case(Case::ScreamingSnake),
+            RenameRule::Kebab => ident.to_case(Case::Kebab),
+            RenameRule::ScreamingKebab => ident.to_case(Case::UpperKebab),
+        }
+    }
+
+    /// Applies the rule to the name of a field, which is assumed to be in snake_case.
+    ///
+    /// The result is the same as with serde's `rename_all` attribute.
+    fn apply_to_field(&self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                lowercase_first_char(&pascal)
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Applies the rule to the name of a variant, which is assumed to be in PascalCase.
+    ///
+    /// The result is the same as with serde's `rename_all` attribute.
+    fn apply_to_variant(&self, variant: &str) -> String {
+        match self {
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Camel => lowercase_first_char(variant),
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (i, c) in variant.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+fn lowercase_first_char(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 /// The value of the `tag` field attribute
 #[derive(Debug, Clone)]
 pub enum TagType {
@@ -264,6 +430,8 @@ pub struct ContainerAttributesInfo {
     pub err_ty: Option<syn::Type>,
     pub tag: TagType,
     pub deny_unknown_fields: Option<DenyUnknownFields>,
+    /// Whether the `#[serde(...)]` attributes should be read as well
+    pub serde_compat: bool,
 
     pub generic_params: Vec<GenericParam>,
     pub where_predicates: Vec<WherePredicate>,
@@ -349,11 +517,29 @@ impl ContainerAttributesInfo {
             self.validate = Some(x);
         }
 
+        self.serde_compat |= other.serde_compat;
+
         self.generic_params.extend(other.generic_params);
         self.where_predicates.extend(other.where_predicates);
 
         Ok(())
     }
+    /// Uses the attributes read from `#[serde(...)]` for the attributes that are not
+    /// given by `self`.
+    fn fill_from_serde(&mut self, serde: Self) {
+        if self.rename_all.is_none() {
+            self.rename_all = serde.rename_all;
+            self.rename_all_span = serde.rename_all_span;
+        }
+        if let (TagType::External, TagType::Internal(_)) = (&self.tag, &serde.tag) {
+            self.tag = serde.tag;
+            self.tag_span = serde.tag_span;
+        }
+        if self.deny_unknown_fields.is_none() {
+            self.deny_unknown_fields = serde.deny_unknown_fields;
+            self.deny_unknown_fields_span = serde.deny_unknown_fields_span;
+        }
+    }
     /// Merges the variant attributes into `self`.
     ///
     /// This is used to combine the container attributes of the whole enum
@@ -365,19 +551,40 @@ impl ContainerAttributesInfo {
 }
 fn parse_rename_all(input: &ParseBuffer) -> Result<RenameAll, syn::Error> {
     let _eq = input.parse::<Token![=]>()?;
-    let ident = input.parse::<Ident>()?;
-    // #[jayson( ... rename_all = ident )]
-    let rename_all = match ident.to_string().as_str() {
-        "camelCase" => RenameAll::CamelCase,
-        "lowercase" => RenameAll::LowerCase,
+    if input.peek(LitStr) {
+        // #[jayson( ... rename_all = "kebab-case" )]
+        let lit = input.parse::<LitStr>()?;
+        rename_all_from_str(&lit.value(), &lit, false)
+    } else {
+        // #[jayson( ... rename_all = ident )]
+        let ident = input.parse::<Ident>()?;
+        rename_all_from_str(&ident.to_string(), &ident, false)
+    }
+}
+
+fn rename_all_from_str(
+    value: &str,
+    span: &impl quote::ToTokens,
+    serde: bool,
+) -> Result<RenameAll, syn::Error> {
+    let rule = match value {
+        "lowercase" => RenameRule::Lower,
+        "UPPERCASE" => RenameRule::Upper,
+        "PascalCase" => RenameRule::Pascal,
+        "camelCase" => RenameRule::Camel,
+        "snake_case" => RenameRule::Snake,
+        "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+        "kebab-case" => RenameRule::Kebab,
+        "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
         _ => {
             return Result::Err(syn::Error::new_spanned(
-                ident,
-                "rename_all can either be equal to `camelCase` or `lowercase`",
+                span,
+                "rename_all must be one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, \
+                 `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, or `SCREAMING-KEBAB-CASE`",
             ));
         }
     };
-    Ok(rename_all)
+    Ok(RenameAll { rule, serde })
 }
 
 fn parse_function_returning_error(
@@ -472,6 +679,7 @@ impl syn::parse::Parse for ContainerAttributesInfo {
                     // #[jayson( ... generic_params = P )]
                     this.generic_params.push(param);
                 }
+                "serde_compat" => this.serde_compat = true,
                 "where_predicate" => {
                     let _eq = input.parse::<Token![=]>()?;
                     let pred = input.parse::<WherePredicate>()?;
@@ -553,12 +761,15 @@ pub fn read_jayson_container_attributes(
             continue;
         }
     }
+    if this.serde_compat {
+        this.fill_from_serde(read_serde_container_attributes(attributes)?);
+    }
     Ok(this)
 }
 
 /// Attributes that are applied to enum variants
 ///
-/// There are currently two supported variant attributes: `rename` and `rename_all`.
+/// There are currently three supported variant attributes: `rename`, `rename_all`, and `alias`.
 /// For example:
 /// ```ignore
 /// enum X {
@@ -572,6 +783,7 @@ pub fn read_jayson_container_attributes(
 pub struct VariantAttributesInfo {
     pub rename_all: Option<RenameAll>,
     pub rename: Option<LitStr>,
+    pub alias: Vec<LitStr>,
     rename_all_span: Option<Span>,
 }
 impl VariantAttributesInfo {
@@ -607,9 +819,24 @@ impl VariantAttributesInfo {
             }
             self.rename = Some(rename)
         }
+        self.alias.extend(other.alias);
 
         Ok(())
     }
+    /// Uses the attributes read from `#[serde(...)]` for the attributes that are not
+    /// given by `self`.
+    fn fill_from_serde(&mut self, serde: Self) {
+        if self.rename_all.is_none() {
+            self.rename_all = serde.rename_all;
+            self.rename_all_span = serde.rename_all_span;
+        }
+        if self.rename.is_none() {
+            self.rename = serde.rename;
+        }
+        if self.alias.is_empty() {
+            self.alias = serde.alias;
+        }
+    }
 }
 impl syn::parse::Parse for VariantAttributesInfo {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -633,6 +860,9 @@ impl syn::parse::Parse for VariantAttributesInfo {
                     this.rename_all = Some(parse_rename_all(&input)?);
                     this.rename_all_span = Some(attr_name.span());
                 }
+                "alias" => {
+                    this.alias.push(parse_rename(&input)?);
+                }
                 _ => {
                     let message = format!("Unknown jayson variant attribute: {}", attr_name);
                     return Result::Err(syn::Error::new_spanned(attr_name, message));
@@ -657,8 +887,11 @@ impl syn::parse::Parse for VariantAttributesInfo {
 
 /// Parses an array of `syn::Attribute` into a single `FieldAttributesInfo` containing the
 /// relevant information for the generation of the code deserialising each field.
+///
+/// If `serde_compat` is true, the `#[serde(...)]` attributes are also read.
 pub fn read_jayson_variant_attributes(
     attributes: &[Attribute],
+    serde_compat: bool,
 ) -> Result<VariantAttributesInfo, syn::Error> {
     let mut this = VariantAttributesInfo::default();
     for attribute in attributes {
@@ -672,5 +905,197 @@ pub fn read_jayson_variant_attributes(
             continue;
         }
     }
+    if serde_compat {
+        this.fill_from_serde(read_serde_variant_attributes(attributes)?);
+    }
     Ok(this)
 }
+
+/// Returns the content of the `///` doc comments, or `None` if there are none.
+///
+/// The lines of the doc comments are joined with newlines, without the leading space
+/// that follows `///`.
+pub fn read_doc_comment(attributes: &[Attribute]) -> Option<String> {
+    let mut lines = vec![];
+    for attribute in attributes {
+        if !attribute.path.is_ident("doc") {
+            continue;
+        }
+        if let Ok(meta) = attribute.parse_meta() {
+            if let Some(lit) = serde_str(&meta) {
+                let line = lit.value();
+                let line = if line.starts_with(' ') {
+                    &line[1..]
+                } else {
+                    &line
+                };
+                lines.push(line.trim_end().to_owned());
+            }
+        }
+    }
+    let doc = lines.join("\n").trim().to_owned();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Returns the list of items inside all the `#[serde(...)]` attributes.
+///
+/// For example, `#[serde(rename = "a", default)]` gives the items `rename = "a"` and `default`.
+fn serde_meta_items(attributes: &[Attribute]) -> Result<Vec<Meta>, syn::Error> {
+    let mut items = vec![];
+    for attribute in attributes {
+        if !attribute.path.is_ident("serde") {
+            continue;
+        }
+        if let Meta::List(list) = attribute.parse_meta()? {
+            for nested in list.nested {
+                if let NestedMeta::Meta(meta) = nested {
+                    items.push(meta);
+                }
+            }
+        }
+    }
+    Ok(items)
+}
+
+/// Returns the string of a serde item such as `alias = "a"`
+fn serde_str(meta: &Meta) -> Option<LitStr> {
+    match meta {
+        Meta::NameValue(syn::MetaNameValue {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the deserialization name of a serde item such as `rename = "a"`
+/// or `rename(deserialize = "a", serialize = "b")`
+fn serde_name(meta: &Meta) -> Option<LitStr> {
+    match meta {
+        Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+            NestedMeta::Meta(meta) if meta.path().is_ident("deserialize") => serde_str(meta),
+            _ => None,
+        }),
+        _ => serde_str(meta),
+    }
+}
+
+/// Reads the field attributes supported by jayson in the `#[serde(...)]` attributes.
+///
+/// The attributes that only affect serialization are ignored, and the other ones
+/// return an error since jayson cannot honour them.
+fn read_serde_field_attributes(
+    attributes: &[Attribute],
+) -> Result<FieldAttributesInfo, syn::Error> {
+    let mut this = FieldAttributesInfo::default();
+    for meta in serde_meta_items(attributes)? {
+        let name = match meta.path().get_ident() {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+        match name.as_str() {
+            "rename" => this.rename = serde_name(&meta),
+            "alias" => this.alias.extend(serde_str(&meta)),
+            "default" => {
+                this.default = match serde_str(&meta) {
+                    // #[serde(default = "path::to::func")]
+                    Some(func) => {
+                        let func = func.parse::<ExprPath>()?;
+                        Some(DefaultFieldAttribute::Function(parse_quote!(#func())))
+                    }
+                    // #[serde(default)]
+                    None => Some(DefaultFieldAttribute::DefaultTrait),
+                };
+                this.default_span = Some(meta.span());
+            }
+            "skip" | "skip_deserializing" => this.skip = true,
+            // these only affect serialization
+            "skip_serializing" | "skip_serializing_if" | "serialize_with" | "bound" | "getter" => {}
+            _ => return Err(unsupported_serde_attribute(&meta, &name)),
+        }
+    }
+    Ok(this)
+}
+
+/// Reads the container attributes supported by jayson in the `#[serde(...)]` attributes.
+///
+/// The attributes that only affect serialization are ignored, and the other ones
+/// return an error since jayson cannot honour them.
+fn read_serde_container_attributes(
+    attributes: &[Attribute],
+) -> Result<ContainerAttributesInfo, syn::Error> {
+    let mut this = ContainerAttributesInfo::default();
+    for meta in serde_meta_items(attributes)? {
+        let name = match meta.path().get_ident() {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+        match name.as_str() {
+            "rename_all" => {
+                if let Some(lit) = serde_name(&meta) {
+                    this.rename_all = Some(rename_all_from_str(&lit.value(), &lit, true)?);
+                    this.rename_all_span = Some(meta.span());
+                }
+            }
+            "tag" => {
+                if let Some(lit) = serde_str(&meta) {
+                    this.tag = TagType::Internal(lit.value());
+                    this.tag_span = Some(meta.span());
+                }
+            }
+            "deny_unknown_fields" => {
+                this.deny_unknown_fields = Some(DenyUnknownFields::DefaultError);
+                this.deny_unknown_fields_span = Some(meta.span());
+            }
+            "content" => return Err(syn::Error::new(
+                meta.span(),
+                "Adjacently tagged enums aren't supported by the DeserializeFromValue derive macro",
+            )),
+            // these only affect serialization, or the name of the type in serde's errors
+            "rename" | "bound" | "crate" | "expecting" | "into" => {}
+            _ => return Err(unsupported_serde_attribute(&meta, &name)),
+        }
+    }
+    Ok(this)
+}
+
+/// Reads the variant attributes supported by jayson in the `#[serde(...)]` attributes.
+///
+/// The attributes that only affect serialization are ignored, and the other ones
+/// return an error since jayson cannot honour them.
+fn read_serde_variant_attributes(
+    attributes: &[Attribute],
+) -> Result<VariantAttributesInfo, syn::Error> {
+    let mut this = VariantAttributesInfo::default();
+    for meta in serde_meta_items(attributes)? {
+        let name = match meta.path().get_ident() {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+        match name.as_str() {
+            "rename" => this.rename = serde_name(&meta),
+            "rename_all" => {
+                if let Some(lit) = serde_name(&meta) {
+                    this.rename_all = Some(rename_all_from_str(&lit.value(), &lit, true)?);
+                    this.rename_all_span = Some(meta.span());
+                }
+            }
+            "alias" => this.alias.extend(serde_str(&meta)),
+            // these only affect serialization
+            "skip_serializing" | "serialize_with" | "bound" => {}
+            _ => return Err(unsupported_serde_attribute(&meta, &name)),
+        }
+    }
+    Ok(this)
+}
+
+/// The error returned for a `#[serde(...)]` item that jayson cannot honour.
+fn unsupported_serde_attribute(meta: &Meta, name: &str) -> syn::Error {
+    syn::Error::new(
+        meta.span(),
+        format!("The `#[serde({name})]` attribute isn't supported by the DeserializeFromValue derive macro"),
+    )
+}
@@ -16,10 +16,35 @@ pub fn generate_derive_tagged_enum_impl(
     // `variant_impls` is the token stream of the code responsible for deserialising
     // all the fields of the enum variants and returning the fully deserialised enum.
     let variants_impls = variants
-        .into_iter()
-        .map(|v| generate_derive_tagged_enum_variant_impl(&info, &v))
+        .iter()
+        .map(|v| generate_derive_tagged_enum_variant_impl(&info, v))
         .collect::<Vec<_>>();
 
+    let variants_descriptions = variants.iter().map(|v| {
+        let VariantInfo {
+            key_name,
+            key_aliases,
+            data,
+            ..
+        } = v;
+        let fields = match data {
+            Unit => quote! { ::std::vec![] },
+            Named(fields) => crate::derive_named_fields::generate_fields_description(fields),
+        };
+        quote! {
+            jayson::VariantDescription {
+                name: #key_name,
+                aliases: &[#(#key_aliases),*],
+                fields: #fields,
+            }
+        }
+    });
+
+    // the keys accepted for the tag, including the aliases
+    let variants_key_names = variants
+        .iter()
+        .flat_map(|v| std::iter::once(&v.key_name).chain(&v.key_aliases));
+
     let CommonDerivedTypeInfo {
         impl_trait_tokens,
         err_ty,
@@ -39,15 +64,16 @@ pub fn generate_derive_tagged_enum_impl(
                                 jayson_location__
                             ))
                         })?;
+                        let tag_span = jayson::IntoValue::span(&tag_value);
                         let tag_value_string = match tag_value.into_value() {
                             jayson::Value::String(x) => x,
                             v @ _ => {
                                 return ::std::result::Result::Err(
-                                    <#err_ty as jayson::DeserializeError>::incorrect_value_kind(
+                                    <#err_ty as jayson::DeserializeError>::incorrect_value(
                                         None,
-                                        v.kind(),
+                                        jayson::ValueSnapshot::of(&v),
                                         &[jayson::ValueKind::String],
-                                        jayson_location__.push_key(#tag)
+                                        jayson_location__.push_key(#tag).with_span(tag_span.as_ref())
                                     )?
                                 );
                             }
@@ -59,11 +85,11 @@ pub fn generate_derive_tagged_enum_impl(
                             // correspond to any valid enum variant name
                             _ => {
                                 ::std::result::Result::Err(
-                                    <#err_ty as jayson::DeserializeError>::unexpected(
+                                    <#err_ty as jayson::DeserializeError>::unknown_variant(
                                         None,
-                                        // TODO: expected one of {expected_tags_list}, found {actual_tag} error message
-                                        "Incorrect tag value",
-                                        jayson_location__
+                                        &tag_value_string,
+                                        &[#(#variants_key_names),*],
+                                        jayson_location__.push_key(#tag).with_span(tag_span.as_ref())
                                     )?
                                 )
                             }
@@ -72,9 +98,9 @@ pub fn generate_derive_tagged_enum_impl(
                     // this is the case where the value is not a map
                     v @ _ => {
                         ::std::result::Result::Err(
-                            <#err_ty as jayson::DeserializeError>::incorrect_value_kind(
+                            <#err_ty as jayson::DeserializeError>::incorrect_value(
                                 None,
-                                v.kind(),
+                                jayson::ValueSnapshot::of(&v),
                                 &[jayson::ValueKind::Map],
                                 jayson_location__
                             )?
@@ -83,6 +109,12 @@ pub fn generate_derive_tagged_enum_impl(
                 }?;
                 #validate
             }
+            fn describe() -> jayson::Description {
+                jayson::Description::TaggedEnum {
+                    tag: #tag,
+                    variants: ::std::vec![#(#variants_descriptions),*],
+                }
+            }
         }
     }
 }
@@ -110,13 +142,15 @@ fn generate_derive_tagged_enum_variant_impl(
         ident: variant_ident,
         data,
         key_name: variant_key_name,
+        key_aliases: variant_key_aliases,
+        ..
     } = variant;
 
     match data {
         Unit => {
             // If the enum variant is a unit variant, there is nothing else to do.
             quote! {
-                #variant_key_name => {
+                #variant_key_name #(| #variant_key_aliases)* => {
                     ::std::result::Result::Ok(Self::#variant_ident)
                 }
             }
@@ -129,7 +163,7 @@ fn generate_derive_tagged_enum_variant_impl(
             );
             // The code here is virtually identical to the code of `generate_derive_struct_impl`
             quote! {
-                #variant_key_name => {
+                #variant_key_name #(| #variant_key_aliases)* => {
                     let mut jayson_error__ = None;
                     #fields_impl
                 }
@@ -15,8 +15,14 @@ pub fn generate_named_fields_impl(
         field_maps,
         missing_field_errors,
         key_names,
+        key_aliases,
         unknown_key,
         needs_predicate: _,
+        has_default: _,
+        field_docs: _,
+        deny_unknown_fields: _,
+        skipped_field_names,
+        skipped_field_defaults,
     } = fields;
     quote! {
         // Start by declaring all the fields as mutable optionals
@@ -34,11 +40,13 @@ pub fn generate_named_fields_impl(
             match jayson_key__.as_str() {
                 // For each known key, look at the corresponding value and try to deserialize it
                 #(
-                    #key_names => {
+                    #key_names #(| #key_aliases)* => {
+                        let jayson_span__ = jayson::IntoValue::span(&jayson_value__);
+                        let jayson_parse__ = jayson::IntoValue::parses_string_scalars(&jayson_value__);
                         #field_names = match
                             <#field_tys as jayson::DeserializeFromValue<#field_errs>>::deserialize_from_value(
                                 jayson::IntoValue::into_value(jayson_value__),
-                                jayson_location__.push_key(jayson_key__.as_str())
+                                jayson_location__.push_key(jayson_key__.as_str()).with_string_scalars(jayson_parse__).with_span(jayson_span__.as_ref())
                             ) {
                                 Ok(x) => jayson::FieldState::Some(x),
                                 Err(e) => {
@@ -74,8 +82,51 @@ pub fn generate_named_fields_impl(
                 #(
                     #field_names : #field_names.map(#field_maps).unwrap(),
                 )*
+                #(
+                    #skipped_field_names : #skipped_field_defaults,
+                )*
             })
         }
 
     }
 }
+
+/// Return a token stream evaluating to the `Vec<jayson::FieldDescription>` of the given fields.
+pub fn generate_fields_description(fields: &NamedFieldsInfo) -> TokenStream {
+    let NamedFieldsInfo {
+        field_tys,
+        field_errs,
+        key_names,
+        key_aliases,
+        has_default,
+        ..
+    } = fields;
+
+    // A field is required when it has no `default` attribute and no default value of its type
+    let required = field_tys
+        .iter()
+        .zip(field_errs.iter())
+        .zip(has_default.iter())
+        .map(|((field_ty, field_err), has_default)| {
+            if *has_default {
+                quote! { false }
+            } else {
+                quote! {
+                    <#field_ty as jayson::DeserializeFromValue<#field_err>>::default().is_none()
+                }
+            }
+        });
+
+    quote! {
+        ::std::vec![
+            #(
+                jayson::FieldDescription {
+                    key: #key_names,
+                    aliases: &[#(#key_aliases),*],
+                    required: #required,
+                    describe: <#field_tys as jayson::DeserializeFromValue<#field_errs>>::describe,
+                }
+            ),*
+        ]
+    }
+}
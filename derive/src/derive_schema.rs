@@ -0,0 +1,288 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, DeriveInput, WherePredicate};
+
+use crate::attribute_parser::TagType;
+use crate::parse_type::{
+    DerivedTypeInfo, NamedFieldsInfo, TraitImplementationInfo, VariantData, VariantInfo,
+};
+
+/// Return a token stream that implements `JsonSchema` for the given derived type.
+///
+/// The derived type is parsed in the same way as for `DeserializeFromValue`, so that the
+/// schema describes exactly the values accepted by the derived `DeserializeFromValue` implementation.
+pub fn generate_derive_json_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = input.ident.clone();
+    let generics = input.generics.clone();
+    let derived_type_info = DerivedTypeInfo::parse(input)?;
+    let doc = derived_type_info.doc;
+
+    let mut new_predicates = generics
+        .type_params()
+        .map::<WherePredicate, _>(|param| {
+            let param = &param.ident;
+            parse_quote!(#param : jayson::JsonSchema)
+        })
+        .collect::<Vec<_>>();
+
+    // Generic types are always inlined, because their name alone does not identify their schema
+    let schema_name = if generics.type_params().next().is_none() {
+        let name = ident.to_string();
+        quote! { ::std::option::Option::Some(::std::string::String::from(#name)) }
+    } else {
+        quote! { ::std::option::Option::None }
+    };
+
+    let body = match derived_type_info.data {
+        TraitImplementationInfo::Struct(fields) => {
+            new_predicates.extend(field_predicates(&fields));
+            generate_object_schema(&fields, None)
+        }
+        TraitImplementationInfo::Enum { tag, variants } => {
+            let tag = match tag {
+                TagType::Internal(tag) => tag,
+                TagType::External => {
+                    return Err(syn::Error::new(
+                        Span::call_site(),
+                        r##"Externally tagged enums are not supported yet by jayson. Add #[jayson(tag = "some_tag_key")]"##,
+                    ))
+                }
+            };
+            for variant in &variants {
+                if let VariantData::Named(fields) = &variant.data {
+                    new_predicates.extend(field_predicates(fields));
+                }
+            }
+            let variants_schemas = variants.iter().map(|v| {
+                let variant_name = v.ident.to_string();
+                let key_name = &v.key_name;
+                let aliases = &v.key_aliases;
+                let schema = with_description(
+                    generate_variant_schema(&tag, v),
+                    v.doc.as_ref().map(String::as_str),
+                );
+                quote! { (#variant_name, &[#key_name #(, #aliases)*][..], { #schema }) }
+            });
+            quote! {
+                let jayson_name__ = jayson_gen__.definition_name::<Self>();
+                let jayson_variants__ = ::std::vec![#(#variants_schemas),*];
+                jayson_gen__.tagged_enum_schema(jayson_name__.as_ref().map(String::as_str), #tag, jayson_variants__)
+            }
+        }
+        TraitImplementationInfo::UserProvidedFunction { from_attr } => {
+            // the accepted values are the ones accepted by the intermediate type
+            let from_ty = from_attr.from_ty;
+            quote! {
+                jayson_gen__.subschema_for::<#from_ty>()
+            }
+        }
+    };
+    let body = with_description(body, doc.as_ref().map(String::as_str));
+
+    let mut bounded_generics = generics;
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .extend(new_predicates);
+    let (impl_generics, ty_generics, where_clause) = bounded_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics jayson::JsonSchema for #ident #ty_generics #where_clause {
+            fn schema_name() -> ::std::option::Option<::std::string::String> {
+                #schema_name
+            }
+            fn json_schema(jayson_gen__: &mut jayson::SchemaGenerator) -> jayson::OwnedValue {
+                #body
+            }
+        }
+    })
+}
+
+/// Return the `FieldTy: JsonSchema` predicates for the fields with the `needs_predicate` attribute
+fn field_predicates(fields: &NamedFieldsInfo) -> Vec<WherePredicate> {
+    fields
+        .field_tys
+        .iter()
+        .zip(fields.needs_predicate.iter())
+        .filter(|(_, pred)| **pred)
+        .map(|(ty, _)| parse_quote!(#ty : jayson::JsonSchema))
+        .collect()
+}
+
+/// Return a token stream evaluating to the given schema with the given description, if any.
+fn with_description(schema: TokenStream, doc: Option<&str>) -> TokenStream {
+    match doc {
+        Some(doc) => quote! {
+            let mut jayson_described__ = { #schema };
+            if let jayson::OwnedValue::Map(jayson_map__) = &mut jayson_described__ {
+                jayson_map__.insert("description", #doc);
+            }
+            jayson_described__
+        },
+        None => schema,
+    }
+}
+
+/// Return a token stream evaluating to the schema of an object with the given fields.
+///
+/// If `tag` is given, the object also has the tag key, whose value must be one of the tag values.
+fn generate_object_schema(fields: &NamedFieldsInfo, tag: Option<(&str, &[String])>) -> TokenStream {
+    let NamedFieldsInfo {
+        field_tys,
+        key_names,
+        key_aliases,
+        has_default,
+        field_docs,
+        deny_unknown_fields,
+        ..
+    } = fields;
+
+    let tag_property = match tag {
+        Some((tag_key, tag_values)) => {
+            let tag_schema = tag_value_schema(tag_values);
+            quote! {
+                #tag_schema
+                jayson_properties__.insert(#tag_key, jayson_tag__);
+                jayson_required__.push(jayson::OwnedValue::from(#tag_key));
+            }
+        }
+        None => quote! {},
+    };
+
+    // A field is required when it has no `default` attribute and its type cannot be missing.
+    // Its aliases are also listed as properties, since they are accepted as well. The key of a
+    // field with aliases is given by exactly one of its keys, or by none of them when the field
+    // is not required, which is expressed with `oneOf` instead of `required`.
+    let properties = field_tys
+        .iter()
+        .zip(key_names.iter())
+        .zip(key_aliases.iter())
+        .zip(has_default.iter())
+        .zip(field_docs.iter())
+        .map(|((((field_ty, key_name), aliases), has_default), doc)| {
+            let is_required = if *has_default {
+                quote! { false }
+            } else {
+                quote! { !<#field_ty as jayson::JsonSchema>::is_optional() }
+            };
+            let required = if aliases.is_empty() {
+                quote! {
+                    if #is_required {
+                        jayson_required__.push(jayson::OwnedValue::from(#key_name));
+                    }
+                }
+            } else {
+                quote! {
+                    let mut jayson_keys__ = ::std::vec::Vec::<jayson::OwnedValue>::new();
+                    for jayson_key__ in &[#key_name #(, #aliases)*] {
+                        let mut jayson_present__ = jayson::OwnedMap::new();
+                        jayson_present__.insert("required", ::std::vec![*jayson_key__]);
+                        jayson_keys__.push(jayson::OwnedValue::Map(jayson_present__));
+                    }
+                    if !(#is_required) {
+                        let mut jayson_any__ = jayson::OwnedMap::new();
+                        jayson_any__.insert("anyOf", jayson_keys__.clone());
+                        let mut jayson_absent__ = jayson::OwnedMap::new();
+                        jayson_absent__.insert("not", jayson_any__);
+                        jayson_keys__.push(jayson::OwnedValue::Map(jayson_absent__));
+                    }
+                    jayson_exclusive__.push(jayson_keys__);
+                }
+            };
+            let property = with_description(
+                quote! { jayson_gen__.subschema_for::<#field_ty>() },
+                doc.as_ref().map(String::as_str),
+            );
+            quote! {
+                jayson_properties__.insert(#key_name, { #property });
+                #(
+                    jayson_properties__.insert(#aliases, { #property });
+                )*
+                #required
+            }
+        });
+
+    let additional_properties = if *deny_unknown_fields {
+        quote! { jayson_schema__.insert("additionalProperties", false); }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        let mut jayson_properties__ = jayson::OwnedMap::new();
+        let mut jayson_required__ = ::std::vec::Vec::<jayson::OwnedValue>::new();
+        // the `oneOf` alternatives of the fields with aliases
+        let mut jayson_exclusive__ = ::std::vec::Vec::<::std::vec::Vec<jayson::OwnedValue>>::new();
+        #tag_property
+        #(#properties)*
+
+        let mut jayson_schema__ = jayson::OwnedMap::new();
+        jayson_schema__.insert("type", "object");
+        jayson_schema__.insert("properties", jayson_properties__);
+        if !jayson_required__.is_empty() {
+            jayson_schema__.insert("required", jayson::OwnedValue::Sequence(jayson_required__));
+        }
+        if jayson_exclusive__.len() == 1 {
+            jayson_schema__.insert("oneOf", jayson_exclusive__.remove(0));
+        } else if !jayson_exclusive__.is_empty() {
+            let jayson_all__ = jayson_exclusive__
+                .into_iter()
+                .map(|jayson_one_of__| {
+                    let mut jayson_schema__ = jayson::OwnedMap::new();
+                    jayson_schema__.insert("oneOf", jayson_one_of__);
+                    jayson::OwnedValue::Map(jayson_schema__)
+                })
+                .collect::<::std::vec::Vec<_>>();
+            jayson_schema__.insert("allOf", jayson_all__);
+        }
+        #additional_properties
+        jayson::OwnedValue::Map(jayson_schema__)
+    }
+}
+
+/// Return a token stream evaluating to the schema of the given enum variant.
+fn generate_variant_schema(tag_key: &str, variant: &VariantInfo) -> TokenStream {
+    let VariantInfo {
+        data,
+        key_name,
+        key_aliases,
+        ..
+    } = variant;
+    // the tag values accepted for the variant, which include its aliases
+    let tag_values = std::iter::once(key_name)
+        .chain(key_aliases)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    match data {
+        VariantData::Unit => {
+            let tag_schema = tag_value_schema(&tag_values);
+            quote! {
+            #tag_schema
+            let mut jayson_properties__ = jayson::OwnedMap::new();
+            jayson_properties__.insert(#tag_key, jayson_tag__);
+
+            let mut jayson_schema__ = jayson::OwnedMap::new();
+            jayson_schema__.insert("type", "object");
+            jayson_schema__.insert("properties", jayson_properties__);
+            jayson_schema__.insert("required", ::std::vec![#tag_key]);
+            jayson::OwnedValue::Map(jayson_schema__)
+            }
+        }
+        VariantData::Named(fields) => generate_object_schema(fields, Some((tag_key, &tag_values))),
+    }
+}
+
+/// Return a token stream defining `jayson_tag__`, the schema of a tag accepting the given values.
+fn tag_value_schema(tag_values: &[String]) -> TokenStream {
+    match tag_values {
+        [tag_value] => quote! {
+            let mut jayson_tag__ = jayson::OwnedMap::new();
+            jayson_tag__.insert("const", #tag_value);
+        },
+        _ => quote! {
+            let mut jayson_tag__ = jayson::OwnedMap::new();
+            jayson_tag__.insert("enum", ::std::vec![#(#tag_values),*]);
+        },
+    }
+}
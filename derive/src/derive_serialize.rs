@@ -0,0 +1,144 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, DeriveInput, WherePredicate};
+
+use crate::attribute_parser::TagType;
+use crate::parse_type::{
+    DerivedTypeInfo, NamedFieldsInfo, TraitImplementationInfo, VariantData, VariantInfo,
+};
+
+/// Return a token stream that implements `SerializeIntoValue` for the given derived type.
+///
+/// The derived type is parsed in the same way as for `DeserializeFromValue`, so that the
+/// `rename`, `rename_all`, `tag`, and `skip` attributes have the same meaning.
+pub fn generate_derive_serialize_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = input.ident.clone();
+    let generics = input.generics.clone();
+    let derived_type_info = DerivedTypeInfo::parse(input)?;
+
+    let mut new_predicates = generics
+        .type_params()
+        .map::<WherePredicate, _>(|param| {
+            let param = &param.ident;
+            parse_quote!(#param : jayson::SerializeIntoValue)
+        })
+        .collect::<Vec<_>>();
+
+    let body = match derived_type_info.data {
+        TraitImplementationInfo::Struct(fields) => {
+            new_predicates.extend(field_predicates(&fields));
+            let NamedFieldsInfo {
+                field_names,
+                key_names,
+                ..
+            } = &fields;
+            quote! {
+                let mut jayson_map__ = jayson::OwnedMap::new();
+                #(
+                    jayson_map__.insert(#key_names, jayson::SerializeIntoValue::serialize_into_value(&self.#field_names));
+                )*
+                jayson::OwnedValue::Map(jayson_map__)
+            }
+        }
+        TraitImplementationInfo::Enum { tag, variants } => {
+            let tag = match tag {
+                TagType::Internal(tag) => tag,
+                TagType::External => {
+                    return Err(syn::Error::new(
+                        Span::call_site(),
+                        r##"Externally tagged enums are not supported yet by jayson. Add #[jayson(tag = "some_tag_key")]"##,
+                    ))
+                }
+            };
+            for variant in &variants {
+                if let VariantData::Named(fields) = &variant.data {
+                    new_predicates.extend(field_predicates(fields));
+                }
+            }
+            let variants_impls = variants
+                .iter()
+                .map(|v| generate_serialize_variant_impl(&tag, v));
+            quote! {
+                let mut jayson_map__ = jayson::OwnedMap::new();
+                match self {
+                    #(#variants_impls)*
+                }
+                jayson::OwnedValue::Map(jayson_map__)
+            }
+        }
+        TraitImplementationInfo::UserProvidedFunction { .. } => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "The `from` attribute isn't supported by the SerializeIntoValue derive macro",
+            ))
+        }
+    };
+
+    let mut bounded_generics = generics;
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .extend(new_predicates);
+    let (impl_generics, ty_generics, where_clause) = bounded_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics jayson::SerializeIntoValue for #ident #ty_generics #where_clause {
+            fn serialize_into_value(&self) -> jayson::OwnedValue {
+                #body
+            }
+        }
+    })
+}
+
+/// Return the `FieldTy: SerializeIntoValue` predicates for the fields with the `needs_predicate` attribute
+fn field_predicates(fields: &NamedFieldsInfo) -> Vec<WherePredicate> {
+    fields
+        .field_tys
+        .iter()
+        .zip(fields.needs_predicate.iter())
+        .filter(|(_, pred)| **pred)
+        .map(|(ty, _)| parse_quote!(#ty : jayson::SerializeIntoValue))
+        .collect()
+}
+
+/// Create the match arm that serializes the given enum variant.
+///
+/// The context of the token stream is:
+///
+/// ```ignore
+/// let mut jayson_map__ = jayson::OwnedMap::new();
+/// match self {
+///     === here ===
+/// }
+/// ```
+fn generate_serialize_variant_impl(tag_key: &str, variant: &VariantInfo) -> TokenStream {
+    let VariantInfo {
+        ident: variant_ident,
+        data,
+        key_name: variant_key_name,
+        ..
+    } = variant;
+
+    match data {
+        VariantData::Unit => quote! {
+            Self::#variant_ident => {
+                jayson_map__.insert(#tag_key, #variant_key_name);
+            }
+        },
+        VariantData::Named(fields) => {
+            let NamedFieldsInfo {
+                field_names,
+                key_names,
+                ..
+            } = fields;
+            quote! {
+                Self::#variant_ident { #(#field_names,)* .. } => {
+                    jayson_map__.insert(#tag_key, #variant_key_name);
+                    #(
+                        jayson_map__.insert(#key_names, jayson::SerializeIntoValue::serialize_into_value(#field_names));
+                    )*
+                }
+            }
+        }
+    }
+}
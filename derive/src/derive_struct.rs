@@ -15,6 +15,8 @@ pub fn generate_derive_struct_impl(
     } = info;
 
     let fields_impl = crate::generate_named_fields_impl(&fields, &err_ty, quote! { Self });
+    let fields_description = crate::derive_named_fields::generate_fields_description(&fields);
+    let deny_unknown_fields = fields.deny_unknown_fields;
 
     quote! {
          #impl_trait_tokens {
@@ -28,9 +30,9 @@ pub fn generate_derive_struct_impl(
                     // this is the case where the value is not a map
                     v @ _ => {
                         ::std::result::Result::Err(
-                            jayson::take_result_content(<#err_ty as jayson::DeserializeError>::incorrect_value_kind(
+                            jayson::take_result_content(<#err_ty as jayson::DeserializeError>::incorrect_value(
                                 None,
-                                v.kind(),
+                                jayson::ValueSnapshot::of(&v),
                                 &[jayson::ValueKind::Map],
                                 jayson_location__
                             ))
@@ -39,6 +41,12 @@ pub fn generate_derive_struct_impl(
                 }?;
                 #validate
             }
+            fn describe() -> jayson::Description {
+                jayson::Description::Struct {
+                    fields: #fields_description,
+                    deny_unknown_fields: #deny_unknown_fields,
+                }
+            }
         }
     }
 }
@@ -47,6 +47,9 @@ pub fn generate_derive_user_function(
                 })?;
                 #validate
             }
+            fn describe() -> jayson::Description {
+                <#from_ty as jayson::DeserializeFromValue<#err_ty>>::describe()
+            }
         }
     }
 }
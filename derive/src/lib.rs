@@ -3,6 +3,8 @@ extern crate proc_macro;
 mod attribute_parser;
 mod derive_enum;
 mod derive_named_fields;
+mod derive_schema;
+mod derive_serialize;
 mod derive_struct;
 mod derive_user_provided_function;
 mod parse_type;
@@ -43,3 +45,23 @@ pub fn derive_deserialize(input: TokenStream) -> TokenStream {
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(SerializeIntoValue, attributes(jayson, serde))]
+pub fn derive_serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match derive_serialize::generate_derive_serialize_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(JsonSchema, attributes(jayson, serde))]
+pub fn derive_json_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match derive_schema::generate_derive_json_schema_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -1,13 +1,14 @@
 use crate::attribute_parser::{
-    read_jayson_container_attributes, read_jayson_field_attributes, read_jayson_variant_attributes,
-    validate_container_attributes, AttributeFrom, ContainerAttributesInfo, DefaultFieldAttribute,
-    DenyUnknownFields, FunctionReturningError, RenameAll, TagType,
+    read_doc_comment, read_jayson_container_attributes, read_jayson_field_attributes,
+    read_jayson_variant_attributes, validate_container_attributes, AttributeFrom,
+    ContainerAttributesInfo, DefaultFieldAttribute, DenyUnknownFields, FunctionReturningError,
+    RenameAll, TagType,
 };
 
-use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
+use std::collections::HashSet;
 use syn::spanned::Spanned;
 use syn::{parse_quote, Data, DeriveInput, WherePredicate};
 
@@ -21,6 +22,8 @@ pub struct DerivedTypeInfo {
     pub common: CommonDerivedTypeInfo,
     /// Information specific to structs or enums
     pub data: TraitImplementationInfo,
+    /// The doc comment of the derived type
+    pub doc: Option<String>,
 }
 
 /// The subset of [`DerivedTypeInfo`] that contains information
@@ -62,6 +65,12 @@ pub struct VariantInfo {
     ///
     /// It is relevant to the `rename` and `rename_all` attributes
     pub key_name: String,
+    /// The other key names that are accepted for this variant.
+    ///
+    /// It is relevant to the `alias` attribute
+    pub key_aliases: Vec<String>,
+    /// The doc comment of the variant
+    pub doc: Option<String>,
 }
 
 /// Contains the information needed to generate the deserialization code
@@ -80,6 +89,7 @@ impl DerivedTypeInfo {
         // First, read the attributes on the derived input
         // e.g. `#[jayson(error = MyError, tag = "mytag", rename_all = camelCase)]`
         let attrs = read_jayson_container_attributes(&input.attrs)?;
+        let doc = read_doc_comment(&input.attrs);
 
         validate_container_attributes(&attrs, &input)?;
 
@@ -116,8 +126,11 @@ impl DerivedTypeInfo {
                 Data::Enum(e) => {
                     // parse a VariantInfo for each variant in the enum
                     let mut parsed_variants = vec![];
+                    // the keys of the variants and their aliases, which must all be different
+                    let mut variant_keys = HashSet::new();
                     for variant in e.variants {
-                        let variant_attrs = read_jayson_variant_attributes(&variant.attrs)?;
+                        let variant_attrs =
+                            read_jayson_variant_attributes(&variant.attrs, attrs.serde_compat)?;
 
                         let renamed = variant_attrs.rename.as_ref().map(|i| i.value());
 
@@ -126,8 +139,17 @@ impl DerivedTypeInfo {
                         let key_name = key_name_for_ident(
                             variant.ident.to_string(),
                             attrs.rename_all.as_ref(),
-                            renamed.as_deref(),
+                            renamed.as_ref().map(String::as_str),
+                            RenameAll::apply_to_variant,
                         );
+                        let key_span = variant_attrs
+                            .rename
+                            .as_ref()
+                            .map_or(variant.ident.span(), |lit| lit.span());
+                        check_unique_key(&mut variant_keys, &key_name, key_span)?;
+                        for alias in &variant_attrs.alias {
+                            check_unique_key(&mut variant_keys, &alias.value(), alias.span())?;
+                        }
 
                         let mut effective_container_attrs = attrs.clone();
                         effective_container_attrs.merge_variant(&variant_attrs);
@@ -143,9 +165,16 @@ impl DerivedTypeInfo {
                     )),
                         syn::Fields::Unit => VariantData::Unit,
                     };
+                        let key_aliases = variant_attrs
+                            .alias
+                            .iter()
+                            .map(|alias| alias.value())
+                            .collect();
                         parsed_variants.push(VariantInfo {
+                            doc: read_doc_comment(&variant.attrs),
                             ident: variant.ident,
                             key_name,
+                            key_aliases,
                             data,
                         });
                     }
@@ -292,6 +321,7 @@ impl DerivedTypeInfo {
                 validate,
             },
             data,
+            doc,
         })
     }
 }
@@ -320,12 +350,24 @@ pub struct NamedFieldsInfo {
     pub field_maps: Vec<TokenStream>,
     pub missing_field_errors: Vec<TokenStream>,
     pub key_names: Vec<String>,
+    /// The other keys accepted for each field, given by the `alias` attribute.
+    pub key_aliases: Vec<Vec<String>>,
 
     pub needs_predicate: Vec<bool>,
+    /// `true` iff the field has the `default` attribute
+    pub has_default: Vec<bool>,
+    /// The doc comment of each field
+    pub field_docs: Vec<Option<String>>,
+    /// The fields with the `skip` attribute, which are not part of the vectors above.
+    pub skipped_field_names: Vec<syn::Ident>,
+    /// The value of each skipped field, given by its `default` attribute or the `Default` trait.
+    pub skipped_field_defaults: Vec<TokenStream>,
     /// A token stream representing the code to handle an unknown field key.
     ///
     /// It is relevant to the `deny_unknown_fields` attribute.
     pub unknown_key: TokenStream,
+    /// `true` iff the `deny_unknown_fields` attribute exists
+    pub deny_unknown_fields: bool,
 }
 
 impl NamedFieldsInfo {
@@ -341,6 +383,8 @@ impl NamedFieldsInfo {
         // the key (in the serialised value) corresponding to the field
         // influenced by the `rename` and `rename_all` attributes
         let mut key_names = vec![];
+        // the other keys corresponding to the field, given by the `alias` attribute
+        let mut key_aliases = vec![];
         // the token stream that give the optional value of the field when its key is missing
         // influenced by the `default` attribute
         let mut field_defaults = vec![];
@@ -352,18 +396,50 @@ impl NamedFieldsInfo {
         let mut field_maps = vec![];
         // `true` iff the field has the needs_predicate attribute
         let mut needs_predicate = vec![];
+        // `true` iff the field has the `default` attribute
+        let mut has_default = vec![];
+        // the doc comment of the field
+        let mut field_docs = vec![];
+        // the fields with the `skip` attribute and the expression giving their value
+        let mut skipped_field_names = vec![];
+        let mut skipped_field_defaults = vec![];
+        // the keys of the fields and their aliases, which must all be different
+        let mut field_keys = HashSet::new();
 
         for field in fields.named.iter() {
             let field_name = field.ident.clone().unwrap();
             let field_ty = &field.ty;
 
-            let attrs = read_jayson_field_attributes(&field.attrs)?;
+            let attrs = read_jayson_field_attributes(&field.attrs, data_attrs.serde_compat)?;
+
+            if attrs.skip {
+                // #[jayson(skip)] => the field is not read from the map at all
+                let default = match &attrs.default {
+                    Some(DefaultFieldAttribute::Function(expr)) => quote! { #expr },
+                    Some(DefaultFieldAttribute::DefaultTrait) | None => {
+                        quote! { ::std::default::Default::default() }
+                    }
+                };
+                skipped_field_names.push(field_name);
+                skipped_field_defaults.push(default);
+                continue;
+            }
+
             let renamed = attrs.rename.as_ref().map(|i| i.value());
             let key_name = key_name_for_ident(
                 field_name.to_string(),
                 data_attrs.rename_all.as_ref(),
-                renamed.as_deref(),
+                renamed.as_ref().map(String::as_str),
+                RenameAll::apply_to_field,
             );
+            let key_span = attrs
+                .rename
+                .as_ref()
+                .map_or(field_name.span(), |lit| lit.span());
+            check_unique_key(&mut field_keys, &key_name, key_span)?;
+            for alias in &attrs.alias {
+                check_unique_key(&mut field_keys, &alias.value(), alias.span())?;
+            }
 
             let field_default = if let Some(default) = &attrs.default {
                 match default {
@@ -424,11 +500,14 @@ impl NamedFieldsInfo {
             field_names.push(field_name);
             field_tys.push(field_ty.clone());
             key_names.push(key_name.clone());
+            key_aliases.push(attrs.alias.iter().map(|alias| alias.value()).collect());
             field_defaults.push(field_default);
             field_errs.push(error);
             field_maps.push(field_map);
             missing_field_errors.push(missing_field_error);
             needs_predicate.push(attrs.needs_predicate);
+            has_default.push(attrs.default.is_some());
+            field_docs.push(read_doc_comment(&field.attrs));
         }
 
         // Create the token stream representing the code to handle an unknown field key.
@@ -437,6 +516,12 @@ impl NamedFieldsInfo {
         // If the #[jayson(deny_unknown_fields)] or #[jayson(deny_unknown_fields = func)] attribute exists,
         // we return an error: either the default error, or an error created by the custom function given by
         // the user.
+        // the keys accepted by the struct, including the aliases
+        let accepted_keys = key_names
+            .iter()
+            .zip(&key_aliases)
+            .flat_map(|(key_name, aliases)| std::iter::once(key_name).chain(aliases))
+            .collect::<Vec<_>>();
         let unknown_key = match &data_attrs.deny_unknown_fields {
             Some(DenyUnknownFields::DefaultError) => {
                 // Here we must give as argument the accepted keys
@@ -444,13 +529,13 @@ impl NamedFieldsInfo {
                     jayson_error__ = ::std::option::Option::Some(<#err_ty as jayson::DeserializeError>::unknown_key(
                         jayson_error__,
                         jayson_key__,
-                        &[#(#key_names),*],
+                        &[#(#accepted_keys),*],
                         jayson_location__
                     )?);
                 }
             }
             Some(DenyUnknownFields::Function(func)) => quote! {
-                let jayson_e__ = #func (jayson_key__, &[#(#key_names),*], jayson_location__) ;
+                let jayson_e__ = #func (jayson_key__, &[#(#accepted_keys),*], jayson_location__) ;
                 jayson_error__ = ::std::option::Option::Some(<#err_ty as jayson::MergeWithError<_>>::merge(
                     jayson_error__,
                     jayson_e__,
@@ -463,28 +548,49 @@ impl NamedFieldsInfo {
             field_names,
             field_tys,
             key_names,
+            key_aliases,
             field_defaults,
             field_errs,
             field_maps,
             needs_predicate,
+            has_default,
+            field_docs,
             missing_field_errors,
             unknown_key,
+            deny_unknown_fields: data_attrs.deny_unknown_fields.is_some(),
+            skipped_field_names,
+            skipped_field_defaults,
         })
     }
 }
 
 /// Transforms the given `ident` string according to the rules of the `rename` and `rename_all` attributes
+///
+/// `apply_rename_all` is either [`RenameAll::apply_to_field`] or [`RenameAll::apply_to_variant`].
 fn key_name_for_ident(
     ident: String,
     rename_all: Option<&RenameAll>,
     rename: Option<&str>,
+    apply_rename_all: fn(&RenameAll, &str) -> String,
 ) -> String {
     match rename {
         Some(name) => name.to_string(),
         None => match rename_all {
-            Some(RenameAll::CamelCase) => ident.to_case(Case::Camel),
-            Some(RenameAll::LowerCase) => ident.to_lowercase(),
+            Some(rename_all) => apply_rename_all(rename_all, &ident),
             None => ident,
         },
     }
 }
+
+/// Records the key of a field or variant, returning an error if another field or variant
+/// already uses it.
+fn check_unique_key(keys: &mut HashSet<String>, key: &str, span: Span) -> syn::Result<()> {
+    if keys.insert(key.to_owned()) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            span,
+            format!("The key `{key}` is used more than once"),
+        ))
+    }
+}
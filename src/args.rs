@@ -0,0 +1,160 @@
+/*!
+Deserialize from command-line arguments.
+
+The following arguments are recognised:
+- `--key value` and `--key=value` set `key` to the string `value`
+- `--flag`, when it is not followed by a value, sets `flag` to `"true"`
+- `--no-flag` sets `flag` to `"false"`
+- `--nested.key=value` sets `key` inside the map `nested`
+
+Dashes within keys are replaced by underscores, so that `--pool-size 10` sets the field
+`pool_size`. A key that is given multiple times is read as a sequence of strings. As with
+[`ParseStrings`], booleans and numbers are parsed from the strings when needed.
+
+## Example
+```
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(DeserializeFromValue)]
+struct Options {
+    verbose: bool,
+    db: Db,
+}
+#[derive(DeserializeFromValue)]
+struct Db {
+    pool_size: u32,
+}
+
+let value = jayson::args::parse(["--verbose", "--db.pool-size", "10"]).unwrap();
+let options = jayson::deserialize::<Options, _, StandardError>(value).unwrap();
+assert!(options.verbose);
+assert_eq!(options.db.pool_size, 10);
+```
+*/
+
+use crate::{OwnedMap, OwnedValue, ParseStrings, ValuePointer, ValuePointerComponent};
+use std::fmt::Display;
+
+/// An error returned when the command-line arguments are malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    /// An argument that is neither an option nor the value of an option
+    UnexpectedArgument(String),
+    /// An option whose key is empty or contains an empty component, such as `--` or `--a..b`
+    InvalidKey(String),
+    /// An option given both as a value and as a map, such as `--a=1 --a.b=2`
+    ConflictingKey(String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            ArgsError::InvalidKey(arg) => write!(f, "invalid option `{arg}`"),
+            ArgsError::ConflictingKey(key) => {
+                write!(
+                    f,
+                    "the option `--{key}` is given both as a value and as a map"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+/// Parse the arguments of the current process, without the name of the program.
+///
+/// Arguments which are not valid unicode are replaced lossily.
+pub fn parse_env() -> Result<ParseStrings<OwnedValue>, ArgsError> {
+    parse(
+        std::env::args_os()
+            .skip(1)
+            .map(|arg| arg.to_string_lossy().into_owned()),
+    )
+}
+
+/// Parse the given arguments. They must not include the name of the program.
+pub fn parse<I, S>(args: I) -> Result<ParseStrings<OwnedValue>, ArgsError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut root = OwnedMap::new();
+    let mut args = args.into_iter().map(Into::into).peekable();
+    while let Some(arg) = args.next() {
+        let option = match arg.strip_prefix("--") {
+            Some(option) => option,
+            None => return Err(ArgsError::UnexpectedArgument(arg)),
+        };
+        let (key, value) = match option.split_once('=') {
+            Some((key, value)) => (key, value.to_owned()),
+            None => {
+                if let Some(negated) = option.strip_prefix("no-") {
+                    (negated, "false".to_owned())
+                } else {
+                    match args.next_if(|next| !next.starts_with("--")) {
+                        Some(value) => (option, value),
+                        None => (option, "true".to_owned()),
+                    }
+                }
+            }
+        };
+        let keys: Vec<String> = key.split('.').map(|k| k.replace('-', "_")).collect();
+        if keys.iter().any(String::is_empty) {
+            return Err(ArgsError::InvalidKey(arg));
+        }
+        insert_nested(&mut root, &keys, value)
+            .map_err(|()| ArgsError::ConflictingKey(key.to_owned()))?;
+    }
+    Ok(ParseStrings(OwnedValue::Map(root)))
+}
+
+/// Return the name of the option corresponding to the given location, such as `--db.pool-size`.
+///
+/// This is useful to refer to the option responsible for a deserialization error.
+pub fn option_name(location: &ValuePointer) -> String {
+    let mut name = String::from("--");
+    for (i, component) in location.path.iter().enumerate() {
+        if i > 0 {
+            name.push('.');
+        }
+        match component {
+            ValuePointerComponent::Key(key) => name.push_str(&key.replace('_', "-")),
+            ValuePointerComponent::Index(index) => name.push_str(&index.to_string()),
+        }
+    }
+    name
+}
+
+fn insert_nested(map: &mut OwnedMap, keys: &[String], value: String) -> Result<(), ()> {
+    let (key, rest) = match keys.split_first() {
+        Some(x) => x,
+        None => return Ok(()),
+    };
+    if rest.is_empty() {
+        let value = OwnedValue::String(value);
+        match map.get_mut(key) {
+            None => {
+                map.insert(key.as_str(), value);
+            }
+            Some(OwnedValue::Map(_)) => return Err(()),
+            Some(OwnedValue::Sequence(values)) => values.push(value),
+            Some(existing) => {
+                let first = std::mem::replace(existing, OwnedValue::Null);
+                *existing = OwnedValue::Sequence(vec![first, value]);
+            }
+        }
+        return Ok(());
+    }
+    match map.get_mut(key) {
+        None => {
+            let mut inner = OwnedMap::new();
+            insert_nested(&mut inner, rest, value)?;
+            map.insert(key.as_str(), inner);
+            Ok(())
+        }
+        Some(OwnedValue::Map(inner)) => insert_nested(inner, rest, value),
+        Some(_) => Err(()),
+    }
+}
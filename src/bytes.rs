@@ -0,0 +1,213 @@
+use crate::{
+    DecodeError, Description, DeserializeError, DeserializeFromValue, ErrorKind, IntoValue, Value,
+    ValueKind, ValuePointerRef, ValueSnapshot,
+};
+
+/// Binary data.
+///
+/// It is deserialized from [`Value::Bytes`] or, for the formats that cannot represent
+/// binary data, from a sequence of integers between 0 and 255.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+/// Binary data encoded as a base64 string.
+///
+/// Both the standard and the URL-safe alphabets are accepted, but not within the same string.
+/// The padding is optional, and the unused bits of the last character must be zero.
+/// [`Value::Bytes`] is also accepted as-is, so that the same type can be deserialized from
+/// formats that can represent binary data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Base64(pub Vec<u8>);
+
+/// Binary data encoded as a hexadecimal string, such as `"00ff"`.
+///
+/// Both lowercase and uppercase digits are accepted. [`Value::Bytes`] is also accepted as-is,
+/// so that the same type can be deserialized from formats that can represent binary data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Hex(pub Vec<u8>);
+
+impl<E> DeserializeFromValue<E> for Bytes
+where
+    E: DeserializeError,
+{
+    fn deserialize_from_value<V: IntoValue>(
+        value: Value<V>,
+        location: ValuePointerRef,
+    ) -> Result<Self, E> {
+        match value {
+            Value::Bytes(bytes) => Ok(Bytes(bytes)),
+            value @ Value::Sequence(_) => {
+                Vec::<u8>::deserialize_from_value(value, location).map(Bytes)
+            }
+            v => Err(E::incorrect_value(
+                None,
+                ValueSnapshot::of(&v),
+                &[ValueKind::Bytes, ValueKind::Sequence],
+                location,
+            )?),
+        }
+    }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Bytes, ValueKind::Sequence])
+    }
+}
+
+impl<E> DeserializeFromValue<E> for Base64
+where
+    E: DeserializeError,
+{
+    fn deserialize_from_value<V: IntoValue>(
+        value: Value<V>,
+        location: ValuePointerRef,
+    ) -> Result<Self, E> {
+        match value {
+            Value::Bytes(bytes) => Ok(Base64(bytes)),
+            Value::String(s) => decode_base64(&s).map(Base64).or_else(|error| {
+                Err(E::invalid_value(
+                    None,
+                    ErrorKind::InvalidBase64(error),
+                    location,
+                )?)
+            }),
+            v => Err(E::incorrect_value(
+                None,
+                ValueSnapshot::of(&v),
+                &[ValueKind::String, ValueKind::Bytes],
+                location,
+            )?),
+        }
+    }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::String, ValueKind::Bytes])
+    }
+}
+
+impl<E> DeserializeFromValue<E> for Hex
+where
+    E: DeserializeError,
+{
+    fn deserialize_from_value<V: IntoValue>(
+        value: Value<V>,
+        location: ValuePointerRef,
+    ) -> Result<Self, E> {
+        match value {
+            Value::Bytes(bytes) => Ok(Hex(bytes)),
+            Value::String(s) => decode_hex(&s).map(Hex).or_else(|error| {
+                Err(E::invalid_value(
+                    None,
+                    ErrorKind::InvalidHex(error),
+                    location,
+                )?)
+            }),
+            v => Err(E::incorrect_value(
+                None,
+                ValueSnapshot::of(&v),
+                &[ValueKind::String, ValueKind::Bytes],
+                location,
+            )?),
+        }
+    }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::String, ValueKind::Bytes])
+    }
+}
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let data = s.trim_end_matches('=');
+    let padding = s.len() - data.len();
+    if data.len() % 4 == 1 || padding > 2 || (padding > 0 && s.len() % 4 != 0) {
+        return Err(DecodeError::InvalidLength);
+    }
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    // whether the URL-safe alphabet is used, once a character specific to one alphabet is seen
+    let mut url_safe = None;
+    for (i, c) in data.char_indices() {
+        let invalid = DecodeError::InvalidCharacter {
+            character: c,
+            position: i,
+        };
+        let digit = match c {
+            'A'..='Z' => c as u32 - 'A' as u32,
+            'a'..='z' => c as u32 - 'a' as u32 + 26,
+            '0'..='9' => c as u32 - '0' as u32 + 52,
+            '+' | '/' | '-' | '_' => {
+                let is_url_safe = c == '-' || c == '_';
+                if *url_safe.get_or_insert(is_url_safe) != is_url_safe {
+                    return Err(invalid);
+                }
+                if c == '+' || c == '-' {
+                    62
+                } else {
+                    63
+                }
+            }
+            _ => return Err(invalid),
+        };
+        buffer = (buffer << 6) | digit;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // the bits left over after the last byte must be zero, so that each
+    // sequence of bytes has a single encoding
+    if buffer != 0 {
+        let (position, character) = data.char_indices().last().unwrap_or_default();
+        return Err(DecodeError::InvalidCharacter {
+            character,
+            position,
+        });
+    }
+    Ok(bytes)
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, DecodeError> {
+    if s.len() % 2 != 0 {
+        return Err(DecodeError::InvalidLength);
+    }
+    let mut bytes = Vec::with_capacity(s.len() / 2);
+    let mut high = None;
+    for (i, c) in s.char_indices() {
+        let digit = match c.to_digit(16) {
+            Some(digit) => digit as u8,
+            None => {
+                return Err(DecodeError::InvalidCharacter {
+                    character: c,
+                    position: i,
+                })
+            }
+        };
+        match high.take() {
+            Some(high) => bytes.push((high << 4) | digit),
+            None => high = Some(digit),
+        }
+    }
+    Ok(bytes)
+}
+
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut s = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(ALPHABET[(buffer >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+    s
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,124 @@
+use crate::flow::{find_key, invalid_value, key_to_string, string_keys, Flow, FlowValue};
+use crate::{DeserializeError, ErrorKind, IntoValue, Value, ValueKind, ValuePointerRef, ValueTree};
+use ciborium::value::{Integer, Value as CValue};
+use std::convert::TryFrom;
+
+impl IntoValue for CValue {
+    type Sequence = Vec<CValue>;
+    type Map = Vec<(String, CValue)>;
+
+    /// The values rejected by [`validate`](IntoValue::validate) are only converted when
+    /// it is skipped: the integers below `i64::MIN` become the closest [`Value::Float`], so
+    /// that no integer type accepts them, and the values of colliding keys are all kept.
+    /// The values added to `ciborium::Value` in future versions are converted to
+    /// [`Value::Null`].
+    fn into_value(self) -> Value<Self> {
+        match self {
+            CValue::Null => Value::Null,
+            CValue::Bool(b) => Value::Boolean(b),
+            CValue::Integer(n) => integer_to_value(n),
+            CValue::Float(x) => Value::Float(x),
+            CValue::Text(x) => Value::String(x),
+            CValue::Bytes(x) => Value::Bytes(x),
+            CValue::Array(x) => Value::Sequence(x),
+            CValue::Map(x) => {
+                Value::Map(x.into_iter().map(|(k, v)| (key_to_string(&k), v)).collect())
+            }
+            CValue::Tag(_, value) => value.into_value(),
+            _ => Value::Null,
+        }
+    }
+
+    fn kind(&self) -> ValueKind {
+        match self {
+            CValue::Null => ValueKind::Null,
+            CValue::Bool(_) => ValueKind::Boolean,
+            CValue::Integer(n) => integer_to_value::<CValue>(*n).kind(),
+            CValue::Float(_) => ValueKind::Float,
+            CValue::Text(_) => ValueKind::String,
+            CValue::Bytes(_) => ValueKind::Bytes,
+            CValue::Array(_) => ValueKind::Sequence,
+            CValue::Map(_) => ValueKind::Map,
+            CValue::Tag(_, value) => value.kind(),
+            _ => ValueKind::Null,
+        }
+    }
+
+    /// Reject the negative integers below `i64::MIN`, and the maps with two keys that are
+    /// equal once converted to strings, such as `1` and `"1"`.
+    fn validate<E: DeserializeError>(&self, location: ValuePointerRef) -> Result<(), E> {
+        match self {
+            CValue::Integer(n) if i128::from(*n) < i128::from(i64::MIN) => {
+                let kind = ErrorKind::IntegerOutOfRange {
+                    value: i128::from(*n),
+                    ty: "i64",
+                };
+                Err(invalid_value(kind, location))
+            }
+            CValue::Array(seq) => {
+                for (i, value) in seq.iter().enumerate() {
+                    value.validate(location.push_index(i))?;
+                }
+                Ok(())
+            }
+            CValue::Map(map) => {
+                for (key, value) in string_keys(map.iter().map(|(k, v)| (k, v)), location)? {
+                    value.validate(location.push_key(&key))?;
+                }
+                Ok(())
+            }
+            CValue::Tag(_, value) => value.validate(location),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Keys are compared once converted to strings, and tags are ignored, as when deserializing.
+impl ValueTree for CValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            CValue::Map(map) => find_key(map.iter().map(|(k, v)| (k, v)), key),
+            CValue::Tag(_, value) => value.get_key(key),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            CValue::Array(seq) => seq.get(index),
+            CValue::Tag(_, value) => value.get_index(index),
+            _ => None,
+        }
+    }
+}
+
+/// Convert a CBOR integer to a `Value`.
+///
+/// CBOR integers range from `-(2^64)` to `2^64 - 1`. The negative integers that do not fit
+/// in an `i64` are converted to the closest float.
+fn integer_to_value<V: IntoValue>(n: Integer) -> Value<V> {
+    if let Ok(n) = u64::try_from(n) {
+        Value::Integer(n)
+    } else if let Ok(n) = i64::try_from(n) {
+        Value::NegativeInteger(n)
+    } else {
+        Value::Float(i128::from(n) as f64)
+    }
+}
+
+/// Byte strings are written as sequences of integers, and tags are ignored.
+impl FlowValue for CValue {
+    fn flow(&self) -> Flow<'_, Self> {
+        match self {
+            CValue::Null => Flow::Text("null".into()),
+            CValue::Bool(b) => Flow::Text(b.to_string().into()),
+            CValue::Integer(n) => Flow::Text(i128::from(*n).to_string().into()),
+            CValue::Float(x) => Flow::Text(x.to_string().into()),
+            CValue::Text(x) => Flow::Text(x.into()),
+            CValue::Bytes(bytes) => Flow::Bytes(bytes),
+            CValue::Array(seq) => Flow::Sequence(seq),
+            CValue::Map(map) => Flow::Map(map.iter().map(|(k, v)| (k, v)).collect()),
+            CValue::Tag(_, value) => value.flow(),
+            _ => Flow::Text("null".into()),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::ValueKind;
+
+/// A description of the values accepted by a type implementing
+/// [`DeserializeFromValue`](crate::DeserializeFromValue).
+///
+/// It is returned by [`DeserializeFromValue::describe`](crate::DeserializeFromValue::describe)
+/// and generated by the derive macro. Unlike a [JSON Schema](crate::JsonSchema), it only describes
+/// the outermost value. The description of the value of a field is obtained by calling its
+/// [`describe`](FieldDescription::describe) function, so that recursive types can be described.
+#[derive(Clone, Debug)]
+pub enum Description {
+    /// Nothing is known about the accepted values.
+    Unknown,
+    /// Any value of the given kinds.
+    Kinds(Vec<ValueKind>),
+    /// `null`, or a value described by the inner description.
+    Nullable(Box<Description>),
+    /// A map with the given fields.
+    Struct {
+        fields: Vec<FieldDescription>,
+        /// Whether keys other than the ones of the fields are rejected.
+        deny_unknown_fields: bool,
+    },
+    /// A map containing a tag, whose value selects one of the variants.
+    TaggedEnum {
+        /// The key of the tag
+        tag: &'static str,
+        variants: Vec<VariantDescription>,
+    },
+}
+
+/// A description of a field of a [`Description::Struct`] or of an enum variant.
+#[derive(Clone, Debug)]
+pub struct FieldDescription {
+    /// The key of the field.
+    pub key: &'static str,
+    /// The other keys that are accepted for the field.
+    pub aliases: &'static [&'static str],
+    /// Whether the field must be present, or has a default value otherwise.
+    pub required: bool,
+    /// The function describing the value of the field.
+    pub describe: fn() -> Description,
+}
+
+/// A description of a variant of a [`Description::TaggedEnum`].
+#[derive(Clone, Debug)]
+pub struct VariantDescription {
+    /// The value of the tag selecting this variant.
+    pub name: &'static str,
+    /// The other values of the tag that select this variant.
+    pub aliases: &'static [&'static str],
+    /// The fields of the variant, in addition to the tag.
+    pub fields: Vec<FieldDescription>,
+}
+
+impl Description {
+    /// The kinds of the accepted values, or `None` if they are unknown.
+    pub fn accepted_kinds(&self) -> Option<Vec<ValueKind>> {
+        match self {
+            Description::Unknown => None,
+            Description::Kinds(kinds) => Some(kinds.clone()),
+            Description::Nullable(inner) => {
+                let mut kinds = inner.accepted_kinds()?;
+                if !kinds.contains(&ValueKind::Null) {
+                    kinds.insert(0, ValueKind::Null);
+                }
+                Some(kinds)
+            }
+            Description::Struct { .. } | Description::TaggedEnum { .. } => {
+                Some(vec![ValueKind::Map])
+            }
+        }
+    }
+
+    /// All the keys accepted in a map, including the aliases and the tag of an enum.
+    ///
+    /// The keys of the fields of all the variants of an enum are returned, without duplicates.
+    pub fn known_keys(&self) -> Vec<&'static str> {
+        let mut keys = vec![];
+        let push_fields = |keys: &mut Vec<&'static str>, fields: &[FieldDescription]| {
+            for field in fields {
+                for key in std::iter::once(&field.key).chain(field.aliases) {
+                    if !keys.contains(key) {
+                        keys.push(key);
+                    }
+                }
+            }
+        };
+        match self {
+            Description::Unknown | Description::Kinds(_) => {}
+            Description::Nullable(inner) => return inner.known_keys(),
+            Description::Struct { fields, .. } => push_fields(&mut keys, fields),
+            Description::TaggedEnum { tag, variants } => {
+                keys.push(tag);
+                for variant in variants {
+                    push_fields(&mut keys, &variant.fields);
+                }
+            }
+        }
+        keys
+    }
+
+    /// Return the field accepting the given key, if any.
+    ///
+    /// For an enum, the field is searched in the variant selected by `tag_value`.
+    pub fn field(&self, key: &str, tag_value: Option<&str>) -> Option<&FieldDescription> {
+        let fields = match self {
+            Description::Struct { fields, .. } => fields,
+            Description::TaggedEnum { .. } => &self.variant(tag_value?)?.fields,
+            Description::Nullable(inner) => return inner.field(key, tag_value),
+            _ => return None,
+        };
+        fields
+            .iter()
+            .find(|field| field.key == key || field.aliases.contains(&key))
+    }
+
+    /// Return the enum variant selected by the given tag value, if any.
+    pub fn variant(&self, tag_value: &str) -> Option<&VariantDescription> {
+        match self {
+            Description::TaggedEnum { variants, .. } => variants
+                .iter()
+                .find(|variant| variant.name == tag_value || variant.aliases.contains(&tag_value)),
+            Description::Nullable(inner) => inner.variant(tag_value),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,392 @@
+/*!
+Render deserialization errors as human-friendly reports, with a snippet of the source text.
+
+The [`Diagnostics`] error type accumulates all the deserialization errors instead of stopping
+at the first one. Each [`Diagnostic`] has a message, a label describing the offending value,
+such as `expected a boolean here`, and sometimes a help line, such as the accepted keys.
+
+[`Diagnostics::render`] then locates each error within the original JSON or JSON5 text,
+either with the [`Span`] captured during deserialization, when deserializing a
+[`Json5Value`](crate::json5::Json5Value), or by resolving the [`ValuePointer`] of the error
+against the parsed text.
+
+## Example
+```
+use jayson::{diagnostics::Diagnostics, DeserializeFromValue};
+
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Config {
+    verbose: bool,
+    port: u16,
+}
+
+let text = r#"{
+  "verbose": "yes",
+  "prt": 7700
+}"#;
+let value: serde_json::Value = serde_json::from_str(text).unwrap();
+let errors = jayson::deserialize::<Config, _, Diagnostics>(value).unwrap_err();
+
+assert_eq!(errors.render(text, "config.json"), r#"error: unknown key `prt`
+ --> config.json:3:10
+  |
+3 |   "prt": 7700
+  |          ^^^^ unknown key `prt`
+  |
+  = help: did you mean `port`? The accepted keys are `verbose`, `port`.
+
+error: expected a boolean, found string "yes"
+ --> config.json:2:14
+  |
+2 |   "verbose": "yes",
+  |              ^^^^^ expected a boolean here
+
+error: missing field `port`
+ --> config.json:1:1
+  |
+1 | {
+  | ^ this map is missing the field `port`
+"#);
+```
+*/
+
+use crate::json5;
+use crate::{
+    did_you_mean, DeserializeError, ErrorKind, IntoValue, MergeWithError, Position, Span,
+    ValueKind, ValuePointer, ValuePointerRef, ValueSnapshot,
+};
+use std::fmt::{Display, Write};
+
+/// A single deserialization error, as displayed in a report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The message of the error, such as `expected a boolean, found string "yes"`
+    pub message: String,
+    /// The description of the offending value, such as `expected a boolean here`
+    pub label: Option<String>,
+    /// A hint on how to fix the error, such as the accepted keys
+    pub help: Option<String>,
+    /// The location of the offending value
+    pub location: ValuePointer,
+    /// The span of the offending value, if it was known during deserialization
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic with the given message, at the given location.
+    pub fn new(message: impl Into<String>, location: ValuePointerRef) -> Self {
+        Self {
+            message: message.into(),
+            label: None,
+            help: None,
+            location: location.to_owned(),
+            span: location.span().cloned(),
+        }
+    }
+
+    /// Set the label describing the offending value. It is written next to the carets
+    /// pointing to the value, when the report can show the source text.
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the hint on how to fix the error, which is written as a `= help:` line at the
+    /// end of the diagnostic in the report.
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+/// A [`DeserializeError`] accumulating all the deserialization errors as [`Diagnostic`]s.
+///
+/// It is displayed as the list of the error messages along with their location. Use
+/// [`render`](Diagnostics::render) to display the errors with a snippet of the source text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// Add a diagnostic to the errors, creating them if needed.
+    fn push(self_: Option<Self>, diagnostic: Diagnostic) -> Result<Self, Self> {
+        let mut errors = self_.unwrap_or_default();
+        errors.diagnostics.push(diagnostic);
+        Ok(errors)
+    }
+
+    /// Render all the errors with a snippet of the source `text` they come from.
+    ///
+    /// `source_name`, such as a file path, is shown next to the line and column of each error,
+    /// unless the span of the error names its own source.
+    pub fn render(&self, text: &str, source_name: &str) -> String {
+        render(&self.diagnostics, text, source_name)
+    }
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, diagnostic) in self.diagnostics.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", diagnostic.message)?;
+            if !diagnostic.location.path.is_empty() {
+                write!(f, " at `{}`", diagnostic.location)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+impl MergeWithError<Diagnostics> for Diagnostics {
+    fn merge(
+        self_: Option<Self>,
+        other: Diagnostics,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let mut errors = self_.unwrap_or_default();
+        errors.diagnostics.extend(other.diagnostics);
+        Ok(errors)
+    }
+}
+
+impl DeserializeError for Diagnostics {
+    fn location(&self) -> Option<ValuePointer> {
+        self.diagnostics
+            .first()
+            .map(|diagnostic| diagnostic.location.clone())
+    }
+
+    fn incorrect_value_kind(
+        self_: Option<Self>,
+        actual: ValueKind,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let expected = expected_kinds(accepted);
+        let diagnostic = Diagnostic::new(
+            format!("expected {expected}, found {}", kind_name(actual)),
+            location,
+        )
+        .with_label(format!("expected {expected} here"));
+        Self::push(self_, diagnostic)
+    }
+
+    fn incorrect_value(
+        self_: Option<Self>,
+        actual: ValueSnapshot,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let expected = expected_kinds(accepted);
+        let diagnostic = Diagnostic::new(format!("expected {expected}, found {actual}"), location)
+            .with_label(format!("expected {expected} here"));
+        Self::push(self_, diagnostic)
+    }
+
+    fn missing_field(
+        self_: Option<Self>,
+        field: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let diagnostic = Diagnostic::new(format!("missing field `{field}`"), location)
+            .with_label(format!("this map is missing the field `{field}`"));
+        Self::push(self_, diagnostic)
+    }
+
+    /// The diagnostic points to the value of the unknown key.
+    fn unknown_key(
+        self_: Option<Self>,
+        key: &str,
+        accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let location = location.push_key(key);
+        let mut diagnostic = Diagnostic::new(format!("unknown key `{key}`"), location)
+            .with_label(format!("unknown key `{key}`"));
+        if let Some(help) = accepted_help("keys", key, accepted) {
+            diagnostic = diagnostic.with_help(help);
+        }
+        Self::push(self_, diagnostic)
+    }
+
+    fn unknown_variant(
+        self_: Option<Self>,
+        found: &str,
+        accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let mut diagnostic = Diagnostic::new(format!("unknown tag value `{found}`"), location)
+            .with_label(format!("unknown tag value `{found}`"));
+        if let Some(help) = accepted_help("tag values", found, accepted) {
+            diagnostic = diagnostic.with_help(help);
+        }
+        Self::push(self_, diagnostic)
+    }
+
+    fn invalid_value(
+        self_: Option<Self>,
+        kind: ErrorKind,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let diagnostic = Diagnostic::new(kind.to_string(), location).with_label("invalid value");
+        Self::push(self_, diagnostic)
+    }
+
+    fn unexpected(self_: Option<Self>, msg: &str, location: ValuePointerRef) -> Result<Self, Self> {
+        Self::push(self_, Diagnostic::new(msg, location))
+    }
+}
+
+/// Render the given diagnostics with a snippet of the source `text` they come from.
+///
+/// This is useful to render the errors of another [`DeserializeError`] implementation, after
+/// converting them to [`Diagnostic`]s. See [`Diagnostics::render`].
+pub fn render(diagnostics: &[Diagnostic], text: &str, source_name: &str) -> String {
+    // the text is only parsed if a diagnostic does not know its span
+    let mut parsed = None;
+    let mut report = String::new();
+    for (i, diagnostic) in diagnostics.iter().enumerate() {
+        if i > 0 {
+            report.push('\n');
+        }
+        let span = diagnostic.span.as_ref();
+        let position = match span.and_then(|span| span.position) {
+            Some(position) => Some(position),
+            None => {
+                let root = parsed.get_or_insert_with(|| json5::from_str(text).ok());
+                root.as_ref()
+                    .and_then(|root| locate(root, &diagnostic.location))
+            }
+        };
+        let source_name = span
+            .and_then(|span| span.source.as_deref())
+            .unwrap_or(source_name);
+        let _ = write_diagnostic(&mut report, diagnostic, position, text, source_name);
+    }
+    report
+}
+
+/// Return the position of the value at `location`, or of its closest existing ancestor.
+fn locate(root: &json5::Json5Value, location: &ValuePointer) -> Option<Position> {
+    let mut location = location.clone();
+    loop {
+        if let Some(value) = location.resolve(root) {
+            return value.span().and_then(|span| span.position);
+        }
+        location.path.pop()?;
+    }
+}
+
+fn write_diagnostic(
+    report: &mut String,
+    diagnostic: &Diagnostic,
+    position: Option<Position>,
+    text: &str,
+    source_name: &str,
+) -> std::fmt::Result {
+    writeln!(report, "error: {}", diagnostic.message)?;
+    // the line of the snippet, along with the line number and the column of the value
+    let snippet = position.and_then(|position| {
+        let before = text.get(..position.start)?;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[position.start..]
+            .find('\n')
+            .map_or(text.len(), |i| position.start + i);
+        let line_number = before.matches('\n').count() + 1;
+        let column = text[line_start..position.start].chars().count() + 1;
+        // multi-line spans are only underlined on their first line
+        let underlined = text
+            .get(position.start..position.end.clamp(position.start, line_end))
+            .map_or(0, |s| s.chars().count())
+            .max(1);
+        Some((&text[line_start..line_end], line_number, column, underlined))
+    });
+    let (line, line_number, column, underlined) = match snippet {
+        Some(snippet) => snippet,
+        None => {
+            writeln!(report, " --> {source_name}")?;
+            if !diagnostic.location.path.is_empty() {
+                writeln!(report, "  = at `{}`", diagnostic.location)?;
+            }
+            if let Some(help) = &diagnostic.help {
+                writeln!(report, "  = help: {help}")?;
+            }
+            return Ok(());
+        }
+    };
+
+    let gutter = " ".repeat(line_number.to_string().len());
+    writeln!(report, "{gutter}--> {source_name}:{line_number}:{column}")?;
+    writeln!(report, "{gutter} |")?;
+    writeln!(report, "{line_number} | {}", line.trim_end_matches('\r'))?;
+    let padding = " ".repeat(column - 1);
+    let carets = "^".repeat(underlined);
+    match &diagnostic.label {
+        Some(label) => writeln!(report, "{gutter} | {padding}{carets} {label}")?,
+        None => writeln!(report, "{gutter} | {padding}{carets}")?,
+    }
+    if let Some(help) = &diagnostic.help {
+        writeln!(report, "{gutter} |")?;
+        writeln!(report, "{gutter} = help: {help}")?;
+    }
+    Ok(())
+}
+
+/// Return the name of a value kind, with an article, such as `a boolean`.
+fn kind_name(kind: ValueKind) -> &'static str {
+    match kind {
+        ValueKind::Null => "null",
+        ValueKind::Boolean => "a boolean",
+        ValueKind::Integer => "an integer",
+        ValueKind::NegativeInteger => "a negative integer",
+        ValueKind::Float => "a float",
+        ValueKind::String => "a string",
+        ValueKind::Bytes => "bytes",
+        ValueKind::Sequence => "a sequence",
+        ValueKind::Map => "a map",
+    }
+}
+
+/// Return the accepted kinds as in `a string or a map`.
+///
+/// Negative integers are not mentioned when integers are accepted as well.
+fn expected_kinds(accepted: &[ValueKind]) -> String {
+    let names = accepted
+        .iter()
+        .filter(|kind| {
+            **kind != ValueKind::NegativeInteger || !accepted.contains(&ValueKind::Integer)
+        })
+        .map(|kind| kind_name(*kind))
+        .collect::<Vec<_>>();
+    match names.split_last() {
+        None => "nothing".to_owned(),
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+    }
+}
+
+/// Return a help line listing the `accepted` strings, and suggesting the one closest to `found`.
+fn accepted_help(what: &str, found: &str, accepted: &[&str]) -> Option<String> {
+    if accepted.is_empty() {
+        return None;
+    }
+    let list = accepted
+        .iter()
+        .map(|a| format!("`{a}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(match did_you_mean(found, accepted) {
+        Some(suggestion) => {
+            format!("did you mean `{suggestion}`? The accepted {what} are {list}.")
+        }
+        None => format!("the accepted {what} are {list}."),
+    })
+}
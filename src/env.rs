@@ -0,0 +1,159 @@
+/*!
+Deserialize from environment variables.
+
+An [`Env`] selects the variables starting with a given prefix and splits the rest of their
+names with a separator to build a nested map. For example, with the prefix `APP` and the
+separator `__`, the variable `APP__DB__POOL_SIZE=10` is read as the value
+`{ "db": { "pool_size": "10" } }`. Keys are lowercased, and booleans and numbers are parsed
+from the strings when needed, as with [`ParseStrings`].
+
+The location of a deserialization error can be converted back to the name of the variable
+that caused it with [`Env::variable_name`].
+
+## Example
+```
+use jayson::{env::Env, DeserializeFromValue, StandardError};
+
+#[derive(DeserializeFromValue)]
+struct Config {
+    db: Db,
+}
+#[derive(DeserializeFromValue)]
+struct Db {
+    pool_size: u32,
+}
+
+let env = Env::new().prefix("APP");
+let value = env.read_vars([("APP__DB__POOL_SIZE", "10"), ("HOME", "/root")]);
+let config = jayson::deserialize::<Config, _, StandardError>(value).unwrap();
+assert_eq!(config.db.pool_size, 10);
+```
+*/
+
+use crate::{OwnedMap, OwnedValue, ParseStrings, ValuePointer, ValuePointerComponent};
+
+/// A builder describing how to read environment variables. See the [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct Env {
+    prefix: Option<String>,
+    separator: String,
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            separator: "__".to_owned(),
+        }
+    }
+}
+
+impl Env {
+    /// Create an `Env` that reads all variables, using `__` as the separator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only read the variables whose name starts with the prefix followed by the separator.
+    ///
+    /// The prefix is removed from the name of the variables. It is matched case-sensitively.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Use the given separator to split variable names into nested keys.
+    ///
+    /// ## Panics
+    /// Panics if the separator is empty.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        let separator = separator.into();
+        assert!(!separator.is_empty(), "the separator must not be empty");
+        self.separator = separator;
+        self
+    }
+
+    /// Read the variables of the current process, given by [`std::env::vars_os`].
+    ///
+    /// Variables whose name or value is not valid unicode are ignored.
+    pub fn read(&self) -> ParseStrings<OwnedValue> {
+        self.read_vars(std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        }))
+    }
+
+    /// Read the given variables instead of the ones of the current process.
+    ///
+    /// If a variable is both a value and a prefix of other variables, such as `APP__DB=1` and
+    /// `APP__DB__PORT=2`, the nested variables take precedence.
+    pub fn read_vars<I, K, V>(&self, vars: I) -> ParseStrings<OwnedValue>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let mut root = OwnedMap::new();
+        for (name, value) in vars {
+            let path = match self.strip_prefix(name.as_ref()) {
+                Some(path) => path,
+                None => continue,
+            };
+            let keys: Vec<String> = path
+                .split(self.separator.as_str())
+                .map(str::to_lowercase)
+                .collect();
+            if keys.iter().any(String::is_empty) {
+                continue;
+            }
+            insert_nested(&mut root, &keys, value.into());
+        }
+        ParseStrings(OwnedValue::Map(root))
+    }
+
+    /// Return the name of the environment variable corresponding to the given location.
+    ///
+    /// This is useful to refer to the variable responsible for a deserialization error.
+    pub fn variable_name(&self, location: &ValuePointer) -> String {
+        let mut components = vec![];
+        if let Some(prefix) = &self.prefix {
+            components.push(prefix.clone());
+        }
+        for component in &location.path {
+            match component {
+                ValuePointerComponent::Key(key) => components.push(key.to_uppercase()),
+                ValuePointerComponent::Index(index) => components.push(index.to_string()),
+            }
+        }
+        components.join(&self.separator)
+    }
+
+    fn strip_prefix<'a>(&self, name: &'a str) -> Option<&'a str> {
+        match &self.prefix {
+            Some(prefix) => name
+                .strip_prefix(prefix.as_str())?
+                .strip_prefix(self.separator.as_str()),
+            None => Some(name),
+        }
+    }
+}
+
+fn insert_nested(map: &mut OwnedMap, keys: &[String], value: String) {
+    let (key, rest) = match keys.split_first() {
+        Some(x) => x,
+        None => return,
+    };
+    if rest.is_empty() {
+        if !matches!(map.get(key), Some(OwnedValue::Map(_))) {
+            map.insert(key.as_str(), value);
+        }
+        return;
+    }
+    if !matches!(map.get(key), Some(OwnedValue::Map(_))) {
+        map.insert(key.as_str(), OwnedMap::new());
+    }
+    if let Some(OwnedValue::Map(inner)) = map.get_mut(key) {
+        insert_nested(inner, rest, value);
+    }
+}
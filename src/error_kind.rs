@@ -0,0 +1,100 @@
+use std::fmt::{self, Display};
+
+/// The cause of an error reported by [`DeserializeError::invalid_value`](crate::DeserializeError::invalid_value).
+///
+/// Each kind carries the parameters needed to describe the error, so that a
+/// [`DeserializeError`](crate::DeserializeError) implementation can render it through its own
+/// message catalog. The [`Display`] implementation is the built-in English rendering.
+///
+/// ## Example
+/// ```
+/// use jayson::ErrorKind;
+///
+/// let kind = ErrorKind::IntegerOutOfRange { value: 300, ty: "u8" };
+/// assert_eq!(kind.to_string(), "Cannot deserialize 300 into a u8");
+///
+/// // a French message catalog
+/// let message = match kind {
+///     ErrorKind::IntegerOutOfRange { value, ty } => format!("Impossible de convertir {value} en {ty}"),
+///     kind => kind.to_string(),
+/// };
+/// assert_eq!(message, "Impossible de convertir 300 en u8");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind<'a> {
+    /// An integer does not fit in the integer type `ty`.
+    IntegerOutOfRange { value: i128, ty: &'static str },
+    /// A float cannot be represented in the target format, such as NaN or an infinity in JSON.
+    UnrepresentableFloat { value: f64, format: &'static str },
+    /// The key of a map could not be parsed into the key type `ty`.
+    InvalidMapKey { key: &'a str, ty: &'static str },
+    /// The map has two keys which are equal once converted to strings, such as the
+    /// integer `1` and the string `"1"`.
+    DuplicateKey { key: &'a str },
+    /// A sequence does not have the number of elements of the tuple it is deserialized into.
+    IncorrectSequenceLength { expected: usize, actual: usize },
+    /// A string could not be decoded as base64.
+    InvalidBase64(DecodeError),
+    /// A string could not be decoded as hexadecimal.
+    InvalidHex(DecodeError),
+}
+
+/// The reason why a string could not be decoded into bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The number of characters is not valid for the encoding.
+    InvalidLength,
+    /// The character at the given byte position is not part of the encoding alphabet.
+    InvalidCharacter { character: char, position: usize },
+}
+
+impl Display for ErrorKind<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::IntegerOutOfRange { value, ty } => {
+                write!(f, "Cannot deserialize {value} into a {ty}")
+            }
+            ErrorKind::UnrepresentableFloat { value, format } => {
+                write!(f, "The float {value} is not representable in {format}")
+            }
+            ErrorKind::InvalidMapKey { key, ty } => write!(
+                f,
+                "The key \"{key}\" could not be deserialized into the key type `{ty}`."
+            ),
+            ErrorKind::DuplicateKey { key } => {
+                write!(f, "The key \"{key}\" appears more than once in the map.")
+            }
+            ErrorKind::IncorrectSequenceLength { expected, actual } => write!(
+                f,
+                "The sequence should have exactly {expected} elements, but it has {actual}."
+            ),
+            ErrorKind::InvalidBase64(error) => {
+                write!(f, "Invalid base64 string: ")?;
+                match error {
+                    DecodeError::InvalidLength => write!(f, "invalid length"),
+                    error => error.fmt(f),
+                }
+            }
+            ErrorKind::InvalidHex(error) => {
+                write!(f, "Invalid hexadecimal string: ")?;
+                match error {
+                    DecodeError::InvalidLength => write!(f, "odd number of digits"),
+                    error => error.fmt(f),
+                }
+            }
+        }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength => write!(f, "invalid length"),
+            DecodeError::InvalidCharacter {
+                character,
+                position,
+            } => write!(f, "invalid character `{character}` at position {position}"),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Conversion of map keys to strings, for the formats whose keys can be any value:
+//! YAML, MessagePack, and CBOR.
+
+use crate::{DeserializeError, ErrorKind, ValuePointerRef};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// A value of a format whose map keys are not necessarily strings.
+pub(crate) trait FlowValue: Sized {
+    /// Describe how `self` is written in flow syntax.
+    fn flow(&self) -> Flow<'_, Self>;
+}
+
+// each format only uses some of the variants
+#[allow(dead_code)]
+pub(crate) enum Flow<'a, V> {
+    /// A value written as-is, such as a string, a number, or `null`
+    Text(Cow<'a, str>),
+    /// Binary data, written as a sequence of integers
+    Bytes(&'a [u8]),
+    Sequence(&'a [V]),
+    Map(Vec<(&'a V, &'a V)>),
+    /// A value preceded by some text, such as a YAML tag
+    Prefixed(Cow<'a, str>, &'a V),
+}
+
+/// Convert a map key to a string.
+///
+/// Strings are kept as-is. Other scalars are converted to their textual representation,
+/// e.g. `true`, `12`, `null`. Binary data, sequences, and maps are converted to a flow syntax,
+/// e.g. `[1, 2]` and `{a: b}`.
+pub(crate) fn key_to_string<V: FlowValue>(key: &V) -> String {
+    match key.flow() {
+        Flow::Text(s) => s.into_owned(),
+        _ => {
+            let mut s = String::new();
+            write_flow(&mut s, key);
+            s
+        }
+    }
+}
+
+fn write_flow<V: FlowValue>(s: &mut String, value: &V) {
+    match value.flow() {
+        Flow::Text(x) => s.push_str(&x),
+        Flow::Bytes(bytes) => write_bytes(s, bytes),
+        Flow::Sequence(seq) => {
+            s.push('[');
+            for (i, x) in seq.iter().enumerate() {
+                if i > 0 {
+                    s.push_str(", ");
+                }
+                write_flow(s, x);
+            }
+            s.push(']');
+        }
+        Flow::Map(map) => {
+            s.push('{');
+            for (i, (k, v)) in map.into_iter().enumerate() {
+                if i > 0 {
+                    s.push_str(", ");
+                }
+                write_flow(s, k);
+                s.push_str(": ");
+                write_flow(s, v);
+            }
+            s.push('}');
+        }
+        Flow::Prefixed(prefix, value) => {
+            s.push_str(&prefix);
+            write_flow(s, value);
+        }
+    }
+}
+
+/// Write binary data as a sequence of integers, e.g. `[1, 2]`.
+pub(crate) fn write_bytes(s: &mut String, bytes: &[u8]) {
+    s.push('[');
+    for (i, b) in bytes.iter().enumerate() {
+        if i > 0 {
+            s.push_str(", ");
+        }
+        write!(s, "{b}").unwrap();
+    }
+    s.push(']');
+}
+
+/// Convert the keys of the given map entries to strings, and report an
+/// [`ErrorKind::DuplicateKey`] if two keys are equal once converted.
+pub(crate) fn string_keys<'a, V, E>(
+    entries: impl IntoIterator<Item = (&'a V, &'a V)>,
+    location: ValuePointerRef,
+) -> Result<Vec<(String, &'a V)>, E>
+where
+    V: FlowValue + 'a,
+    E: DeserializeError,
+{
+    let entries = entries.into_iter();
+    let mut keys = HashSet::with_capacity(entries.size_hint().0);
+    let mut result = Vec::with_capacity(entries.size_hint().0);
+    for (key, value) in entries {
+        let key = key_to_string(key);
+        if !keys.insert(key.clone()) {
+            let kind = ErrorKind::DuplicateKey { key: &key };
+            return Err(invalid_value(kind, location.push_key(&key)));
+        }
+        result.push((key, value));
+    }
+    Ok(result)
+}
+
+/// Return the value of the first entry whose key is equal to `key` once converted to a string,
+/// as done by [`key_to_string`].
+pub(crate) fn find_key<'a, V: FlowValue + 'a>(
+    entries: impl IntoIterator<Item = (&'a V, &'a V)>,
+    key: &str,
+) -> Option<&'a V> {
+    entries
+        .into_iter()
+        .find(|(k, _)| match k.flow() {
+            Flow::Text(k) => k == key,
+            _ => key_to_string(*k) == key,
+        })
+        .map(|(_, v)| v)
+}
+
+/// Create the error returned by [`IntoValue::validate`](crate::IntoValue::validate).
+pub(crate) fn invalid_value<E: DeserializeError>(kind: ErrorKind, location: ValuePointerRef) -> E {
+    match E::invalid_value(None, kind, location) {
+        Ok(e) | Err(e) => e,
+    }
+}
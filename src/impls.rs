@@ -1,6 +1,6 @@
 use crate::{
-    DeserializeError, DeserializeFromValue, IntoValue, Map, Sequence, Value, ValueKind,
-    ValuePointerRef,
+    Description, DeserializeError, DeserializeFromValue, ErrorKind, IntoValue, Map, Sequence,
+    Value, ValueKind, ValuePointerRef, ValueSnapshot,
 };
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
@@ -42,6 +42,27 @@ where
     }
 }
 
+impl<T> Map for Vec<(String, T)>
+where
+    T: IntoValue,
+{
+    type Value = T;
+    type Iter = <Self as IntoIterator>::IntoIter;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Self::Value> {
+        let index = self.iter().position(|(k, _)| k == key)?;
+        Some(Vec::remove(self, index).1)
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        <Self as IntoIterator>::into_iter(self)
+    }
+}
+
 impl<E> DeserializeFromValue<E> for ()
 where
     E: DeserializeError,
@@ -52,14 +73,38 @@ where
     ) -> Result<Self, E> {
         match value {
             Value::Null => Ok(()),
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Null],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Null])
+    }
+}
+
+/// Whether strings can be parsed into booleans and numbers at the given location, because
+/// [`PARSE_STRING_SCALARS`](IntoValue::PARSE_STRING_SCALARS) is true for `V` or because the
+/// value found there enables it, see [`IntoValue::parses_string_scalars`].
+fn parses_string_scalars<V: IntoValue>(location: ValuePointerRef) -> bool {
+    V::PARSE_STRING_SCALARS || location.parses_string_scalars()
+}
+
+/// Parse a string into a `T`, if strings can be parsed at the given location.
+///
+/// `None` is returned for strings that do not represent a `T`, so that the error reported for
+/// them is about the original string.
+fn parse_string_scalar<V: IntoValue, T: FromStr>(
+    value: &Value<V>,
+    location: ValuePointerRef,
+) -> Option<T> {
+    match value {
+        Value::String(s) if parses_string_scalars::<V>(location) => T::from_str(s).ok(),
+        _ => None,
+    }
 }
 
 impl<E> DeserializeFromValue<E> for bool
@@ -70,16 +115,22 @@ where
         value: Value<V>,
         location: ValuePointerRef,
     ) -> Result<Self, E> {
+        if let Some(b) = parse_string_scalar::<V, bool>(&value, location) {
+            return Ok(b);
+        }
         match value {
             Value::Boolean(b) => Ok(b),
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Boolean],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Boolean])
+    }
 }
 
 macro_rules! deserialize_impl_integer {
@@ -92,34 +143,50 @@ macro_rules! deserialize_impl_integer {
                 value: Value<V>,
                 location: ValuePointerRef,
             ) -> Result<Self, E> {
-                let err = |kind: ValueKind| -> Result<E, E> {
-                    E::incorrect_value_kind(None, kind, &[ValueKind::Integer], location)
+                if let Some(x) = parse_string_scalar::<V, i128>(&value, location) {
+                    return <$t>::try_from(x).or_else(|_| {
+                        Err(E::invalid_value(
+                            None,
+                            ErrorKind::IntegerOutOfRange {
+                                value: x,
+                                ty: std::any::type_name::<$t>(),
+                            },
+                            location,
+                        )?)
+                    });
+                }
+                let err = |actual: ValueSnapshot| -> Result<E, E> {
+                    E::incorrect_value(None, actual, &[ValueKind::Integer], location)
                 };
 
                 match value {
                     Value::Integer(x) => <$t>::try_from(x).or_else(|_| {
-                        Err(E::unexpected(
+                        Err(E::invalid_value(
                             None,
-                            &format!(
-                                "Cannot deserialize {x} into a {}",
-                                std::any::type_name::<$t>()
-                            ),
+                            ErrorKind::IntegerOutOfRange {
+                                value: i128::from(x),
+                                ty: std::any::type_name::<$t>(),
+                            },
                             location,
                         )?)
                     }),
                     Value::NegativeInteger(x) => <$t>::try_from(x).or_else(|_| {
-                        Err(E::unexpected(
+                        Err(E::invalid_value(
                             None,
-                            &format!(
-                                "Cannot deserialize {x} into a {}",
-                                std::any::type_name::<$t>()
-                            ),
+                            ErrorKind::IntegerOutOfRange {
+                                value: i128::from(x),
+                                ty: std::any::type_name::<$t>(),
+                            },
                             location,
                         )?)
                     }),
-                    v => Err(err(v.kind())?),
+                    v => Err(err(ValueSnapshot::of(&v))?),
                 }
             }
+
+            fn describe() -> Description {
+                Description::Kinds(vec![ValueKind::Integer])
+            }
         }
     };
 }
@@ -139,10 +206,22 @@ macro_rules! deserialize_impl_negative_integer {
                 value: Value<V>,
                 location: ValuePointerRef,
             ) -> Result<Self, E> {
-                let err = |kind: ValueKind| {
-                    E::incorrect_value_kind(
+                if let Some(x) = parse_string_scalar::<V, i128>(&value, location) {
+                    return <$t>::try_from(x).or_else(|_| {
+                        Err(E::invalid_value(
+                            None,
+                            ErrorKind::IntegerOutOfRange {
+                                value: x,
+                                ty: std::any::type_name::<$t>(),
+                            },
+                            location,
+                        )?)
+                    });
+                }
+                let err = |actual: ValueSnapshot| {
+                    E::incorrect_value(
                         None,
-                        kind,
+                        actual,
                         &[ValueKind::Integer, ValueKind::NegativeInteger],
                         location,
                     )
@@ -150,28 +229,32 @@ macro_rules! deserialize_impl_negative_integer {
 
                 match value {
                     Value::Integer(x) => <$t>::try_from(x).or_else(|_| {
-                        Err(E::unexpected(
+                        Err(E::invalid_value(
                             None,
-                            &format!(
-                                "Cannot deserialize {x} into a {}",
-                                std::any::type_name::<$t>()
-                            ),
+                            ErrorKind::IntegerOutOfRange {
+                                value: i128::from(x),
+                                ty: std::any::type_name::<$t>(),
+                            },
                             location,
                         )?)
                     }),
                     Value::NegativeInteger(x) => <$t>::try_from(x).or_else(|_| {
-                        Err(E::unexpected(
+                        Err(E::invalid_value(
                             None,
-                            &format!(
-                                "Cannot deserialize {x} into a {}",
-                                std::any::type_name::<$t>()
-                            ),
+                            ErrorKind::IntegerOutOfRange {
+                                value: i128::from(x),
+                                ty: std::any::type_name::<$t>(),
+                            },
                             location,
                         )?)
                     }),
-                    v => Err(err(v.kind())?),
+                    v => Err(err(ValueSnapshot::of(&v))?),
                 }
             }
+
+            fn describe() -> Description {
+                Description::Kinds(vec![ValueKind::Integer, ValueKind::NegativeInteger])
+            }
         }
     };
 }
@@ -192,6 +275,11 @@ macro_rules! deserialize_impl_float {
                 value: Value<V>,
                 location: ValuePointerRef,
             ) -> Result<Self, E> {
+                if let Some(x) =
+                    parse_string_scalar::<V, f64>(&value, location).filter(|x| x.is_finite())
+                {
+                    return Ok(x as $t);
+                }
                 match value {
                     Value::Integer(x) => {
                         return Ok(x as $t);
@@ -203,9 +291,9 @@ macro_rules! deserialize_impl_float {
                         return Ok(x as $t);
                     }
                     v => {
-                        return Err(E::incorrect_value_kind(
+                        return Err(E::incorrect_value(
                             None,
-                            v.kind(),
+                            ValueSnapshot::of(&v),
                             &[
                                 ValueKind::Float,
                                 ValueKind::Integer,
@@ -216,6 +304,14 @@ macro_rules! deserialize_impl_float {
                     }
                 };
             }
+
+            fn describe() -> Description {
+                Description::Kinds(vec![
+                    ValueKind::Float,
+                    ValueKind::Integer,
+                    ValueKind::NegativeInteger,
+                ])
+            }
         }
     };
 }
@@ -232,14 +328,17 @@ where
     ) -> Result<Self, E> {
         match value {
             Value::String(x) => Ok(x),
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::String],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::String])
+    }
 }
 
 impl<T, E> DeserializeFromValue<E> for Vec<T>
@@ -256,8 +355,15 @@ where
                 let mut error = None;
                 let mut vec = Vec::with_capacity(seq.len());
                 for (index, value) in seq.into_iter().enumerate() {
-                    let result =
-                        T::deserialize_from_value(value.into_value(), location.push_index(index));
+                    let span = value.span();
+                    let parse = value.parses_string_scalars();
+                    let result = T::deserialize_from_value(
+                        value.into_value(),
+                        location
+                            .push_index(index)
+                            .with_string_scalars(parse)
+                            .with_span(span.as_ref()),
+                    );
                     match result {
                         Ok(value) => {
                             vec.push(value);
@@ -273,14 +379,44 @@ where
                     Ok(vec)
                 }
             }
-            v => Err(E::incorrect_value_kind(
+            Value::Bytes(bytes) => {
+                let mut error = None;
+                let mut vec = Vec::with_capacity(bytes.len());
+                for (index, byte) in IntoIterator::into_iter(bytes).enumerate() {
+                    let result = T::deserialize_from_value(
+                        Value::<V>::Integer(byte as u64),
+                        location.push_index(index),
+                    );
+                    match result {
+                        Ok(value) => {
+                            vec.push(value);
+                        }
+                        Err(e) => {
+                            error = Some(E::merge(error, e, location.push_index(index))?);
+                        }
+                    }
+                }
+                if let Some(e) = error {
+                    Err(e)
+                } else {
+                    Ok(vec)
+                }
+            }
+            Value::String(x) if parses_string_scalars::<V>(location) => {
+                T::deserialize_from_value(Value::<V>::String(x), location).map(|x| vec![x])
+            }
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Sequence],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        // binary data is read as a sequence of integers
+        Description::Kinds(vec![ValueKind::Sequence, ValueKind::Bytes])
+    }
 }
 
 impl<T, E> DeserializeFromValue<E> for Option<T>
@@ -300,6 +436,9 @@ where
     fn default() -> Option<Self> {
         Some(None)
     }
+    fn describe() -> Description {
+        Description::Nullable(Box::new(T::describe()))
+    }
 }
 
 impl<T, E> DeserializeFromValue<E> for Box<T>
@@ -313,6 +452,9 @@ where
     ) -> Result<Self, E> {
         T::deserialize_from_value(value, location).map(Box::new)
     }
+    fn describe() -> Description {
+        T::describe()
+    }
 }
 
 impl<Key, T, E> DeserializeFromValue<E> for HashMap<Key, T>
@@ -332,9 +474,14 @@ where
                 for (string_key, value) in map.into_iter() {
                     match Key::from_str(&string_key) {
                         Ok(key) => {
+                            let span = value.span();
+                            let parse = value.parses_string_scalars();
                             match T::deserialize_from_value(
                                 value.into_value(),
-                                location.push_key(&string_key),
+                                location
+                                    .push_key(&string_key)
+                                    .with_string_scalars(parse)
+                                    .with_span(span.as_ref()),
                             ) {
                                 Ok(value) => {
                                     res.insert(key, value);
@@ -346,23 +493,30 @@ where
                             }
                         }
                         Err(_) => {
-                            error = Some(E::unexpected(error,&format!(
-                                "The key \"{string_key}\" could not be deserialized into the key type `{}`.",
-                                std::any::type_name::<Key>()
-                            ), location)?);
+                            error = Some(E::invalid_value(
+                                error,
+                                ErrorKind::InvalidMapKey {
+                                    key: &string_key,
+                                    ty: std::any::type_name::<Key>(),
+                                },
+                                location,
+                            )?);
                         }
                     }
                 }
                 Ok(res)
             }
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Map],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Map])
+    }
 }
 
 impl<Key, T, E> DeserializeFromValue<E> for BTreeMap<Key, T>
@@ -382,9 +536,14 @@ where
                 for (string_key, value) in map.into_iter() {
                     match Key::from_str(&string_key) {
                         Ok(key) => {
+                            let span = value.span();
+                            let parse = value.parses_string_scalars();
                             match T::deserialize_from_value(
                                 value.into_value(),
-                                location.push_key(&string_key),
+                                location
+                                    .push_key(&string_key)
+                                    .with_string_scalars(parse)
+                                    .with_span(span.as_ref()),
                             ) {
                                 Ok(value) => {
                                     res.insert(key, value);
@@ -396,23 +555,30 @@ where
                             }
                         }
                         Err(_) => {
-                            error = Some(E::unexpected(error,&format!(
-                                "The key \"{string_key}\" could not be deserialized into the key type `{}`.",
-                                std::any::type_name::<Key>()
-                            ), location)?);
+                            error = Some(E::invalid_value(
+                                error,
+                                ErrorKind::InvalidMapKey {
+                                    key: &string_key,
+                                    ty: std::any::type_name::<Key>(),
+                                },
+                                location,
+                            )?);
                         }
                     }
                 }
                 Ok(res)
             }
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Map],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Map])
+    }
 }
 
 impl<T, E> DeserializeFromValue<E> for HashSet<T>
@@ -429,8 +595,15 @@ where
                 let mut error = None;
                 let mut set = HashSet::with_capacity(seq.len());
                 for (index, value) in seq.into_iter().enumerate() {
-                    let result =
-                        T::deserialize_from_value(value.into_value(), location.push_index(index));
+                    let span = value.span();
+                    let parse = value.parses_string_scalars();
+                    let result = T::deserialize_from_value(
+                        value.into_value(),
+                        location
+                            .push_index(index)
+                            .with_string_scalars(parse)
+                            .with_span(span.as_ref()),
+                    );
                     match result {
                         Ok(value) => {
                             set.insert(value);
@@ -446,14 +619,21 @@ where
                     Ok(set)
                 }
             }
-            v => Err(E::incorrect_value_kind(
+            Value::String(x) if parses_string_scalars::<V>(location) => {
+                T::deserialize_from_value(Value::<V>::String(x), location)
+                    .map(|x| std::iter::once(x).collect())
+            }
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Sequence],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Sequence])
+    }
 }
 
 impl<T, E> DeserializeFromValue<E> for BTreeSet<T>
@@ -470,8 +650,15 @@ where
                 let mut error = None;
                 let mut set = BTreeSet::new();
                 for (index, value) in seq.into_iter().enumerate() {
-                    let result =
-                        T::deserialize_from_value(value.into_value(), location.push_index(index));
+                    let span = value.span();
+                    let parse = value.parses_string_scalars();
+                    let result = T::deserialize_from_value(
+                        value.into_value(),
+                        location
+                            .push_index(index)
+                            .with_string_scalars(parse)
+                            .with_span(span.as_ref()),
+                    );
                     match result {
                         Ok(value) => {
                             set.insert(value);
@@ -487,14 +674,21 @@ where
                     Ok(set)
                 }
             }
-            v => Err(E::incorrect_value_kind(
+            Value::String(x) if parses_string_scalars::<V>(location) => {
+                T::deserialize_from_value(Value::<V>::String(x), location)
+                    .map(|x| std::iter::once(x).collect())
+            }
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Sequence],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Sequence])
+    }
 }
 
 impl<A, B, E> DeserializeFromValue<E> for (A, B)
@@ -511,18 +705,27 @@ where
             Value::Sequence(seq) => {
                 let len = seq.len();
                 if len != 2 {
-                    return Err(E::unexpected(
+                    return Err(E::invalid_value(
                         None,
-                        "The sequence should have exactly 2 elements.",
+                        ErrorKind::IncorrectSequenceLength {
+                            expected: 2,
+                            actual: len,
+                        },
                         location,
                     )?);
                 }
                 let mut error = None;
                 let mut iter = seq.into_iter();
 
+                let a = iter.next().unwrap();
+                let span = a.span();
+                let parse = a.parses_string_scalars();
                 let a = A::deserialize_from_value(
-                    iter.next().unwrap().into_value(),
-                    location.push_index(0),
+                    a.into_value(),
+                    location
+                        .push_index(0)
+                        .with_string_scalars(parse)
+                        .with_span(span.as_ref()),
                 );
                 let a = match a {
                     Ok(a) => Some(a),
@@ -531,9 +734,15 @@ where
                         None
                     }
                 };
+                let b = iter.next().unwrap();
+                let span = b.span();
+                let parse = b.parses_string_scalars();
                 let b = B::deserialize_from_value(
-                    iter.next().unwrap().into_value(),
-                    location.push_index(1),
+                    b.into_value(),
+                    location
+                        .push_index(1)
+                        .with_string_scalars(parse)
+                        .with_span(span.as_ref()),
                 );
                 let b = match b {
                     Ok(b) => Some(b),
@@ -549,14 +758,17 @@ where
                     Ok((a.unwrap(), b.unwrap()))
                 }
             }
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Sequence],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Sequence])
+    }
 }
 
 impl<A, B, C, E> DeserializeFromValue<E> for (A, B, C)
@@ -573,19 +785,28 @@ where
         match value {
             Value::Sequence(seq) => {
                 let len = seq.len();
-                if len != 2 {
-                    return Err(E::unexpected(
+                if len != 3 {
+                    return Err(E::invalid_value(
                         None,
-                        "The sequence should have exactly 2 elements.",
+                        ErrorKind::IncorrectSequenceLength {
+                            expected: 3,
+                            actual: len,
+                        },
                         location,
                     )?);
                 }
                 let mut error = None;
                 let mut iter = seq.into_iter();
 
+                let a = iter.next().unwrap();
+                let span = a.span();
+                let parse = a.parses_string_scalars();
                 let a = A::deserialize_from_value(
-                    iter.next().unwrap().into_value(),
-                    location.push_index(0),
+                    a.into_value(),
+                    location
+                        .push_index(0)
+                        .with_string_scalars(parse)
+                        .with_span(span.as_ref()),
                 );
                 let a = match a {
                     Ok(a) => Some(a),
@@ -594,9 +815,15 @@ where
                         None
                     }
                 };
+                let b = iter.next().unwrap();
+                let span = b.span();
+                let parse = b.parses_string_scalars();
                 let b = B::deserialize_from_value(
-                    iter.next().unwrap().into_value(),
-                    location.push_index(1),
+                    b.into_value(),
+                    location
+                        .push_index(1)
+                        .with_string_scalars(parse)
+                        .with_span(span.as_ref()),
                 );
                 let b = match b {
                     Ok(b) => Some(b),
@@ -605,9 +832,15 @@ where
                         None
                     }
                 };
+                let c = iter.next().unwrap();
+                let span = c.span();
+                let parse = c.parses_string_scalars();
                 let c = C::deserialize_from_value(
-                    iter.next().unwrap().into_value(),
-                    location.push_index(2),
+                    c.into_value(),
+                    location
+                        .push_index(2)
+                        .with_string_scalars(parse)
+                        .with_span(span.as_ref()),
                 );
                 let c = match c {
                     Ok(c) => Some(c),
@@ -623,12 +856,15 @@ where
                     Ok((a.unwrap(), b.unwrap(), c.unwrap()))
                 }
             }
-            v => Err(E::incorrect_value_kind(
+            v => Err(E::incorrect_value(
                 None,
-                v.kind(),
+                ValueSnapshot::of(&v),
                 &[ValueKind::Sequence],
                 location,
             )?),
         }
     }
+    fn describe() -> Description {
+        Description::Kinds(vec![ValueKind::Sequence])
+    }
 }
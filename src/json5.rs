@@ -0,0 +1,617 @@
+/*!
+Parse [JSON5](https://json5.org) documents, with the position of each value.
+
+JSON5 is a superset of JSON meant to be written by humans. It allows comments, trailing
+commas, unquoted keys, single-quoted strings, hexadecimal numbers, and more. This makes it
+convenient for configuration files.
+
+Every [`Json5Value`] knows its [`Position`](crate::Position) within the parsed text, so the location of a
+deserialization error can be reported as a line and column through
+[`ValuePointerRef::span`](crate::ValuePointerRef::span). Syntax errors are reported with a
+line and column as well, see [`ParseError`].
+
+## Example
+```
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(DeserializeFromValue)]
+struct Config {
+    port: u16,
+    hosts: Vec<String>,
+}
+
+let value = jayson::json5::from_str(r#"
+{
+    // the port of the HTTP server
+    port: 0x1E61,
+    hosts: ['localhost', "127.0.0.1",],
+}
+"#).unwrap();
+let config = jayson::deserialize::<Config, _, StandardError>(value).unwrap();
+assert_eq!(config.port, 7777);
+```
+*/
+
+use crate::{IntoValue, Position, Span, Value, ValueKind, ValueTree};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The maximum nesting depth of arrays and objects
+const RECURSION_LIMIT: usize = 128;
+
+/// A value parsed from a JSON5 document, along with its position in the document.
+///
+/// Objects keep the order of their keys. When a key is repeated, the last value wins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Json5Value {
+    node: Node,
+    position: Position,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Null,
+    Boolean(bool),
+    Integer(u64),
+    NegativeInteger(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Json5Value>),
+    Object(Vec<(String, Json5Value)>),
+}
+
+impl IntoValue for Json5Value {
+    type Sequence = Vec<Json5Value>;
+    type Map = Vec<(String, Json5Value)>;
+
+    fn kind(&self) -> ValueKind {
+        match self.node {
+            Node::Null => ValueKind::Null,
+            Node::Boolean(_) => ValueKind::Boolean,
+            Node::Integer(_) => ValueKind::Integer,
+            Node::NegativeInteger(_) => ValueKind::NegativeInteger,
+            Node::Float(_) => ValueKind::Float,
+            Node::String(_) => ValueKind::String,
+            Node::Array(_) => ValueKind::Sequence,
+            Node::Object(_) => ValueKind::Map,
+        }
+    }
+
+    fn into_value(self) -> Value<Self> {
+        match self.node {
+            Node::Null => Value::Null,
+            Node::Boolean(b) => Value::Boolean(b),
+            Node::Integer(x) => Value::Integer(x),
+            Node::NegativeInteger(x) => Value::NegativeInteger(x),
+            Node::Float(x) => Value::Float(x),
+            Node::String(x) => Value::String(x),
+            Node::Array(x) => Value::Sequence(x),
+            Node::Object(x) => Value::Map(x),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        Some(Span::from(self.position))
+    }
+}
+
+impl ValueTree for Json5Value {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match &self.node {
+            // the last value of a repeated key wins
+            Node::Object(entries) => entries.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match &self.node {
+            Node::Array(elements) => elements.get(index),
+            _ => None,
+        }
+    }
+}
+
+/// A syntax error in a JSON5 document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Description of the error, such as ``unexpected character `]` ``
+    pub message: String,
+    /// Byte offset of the error
+    pub offset: usize,
+    /// Line of the error, starting at 1
+    pub line: usize,
+    /// Column of the error, in characters, starting at 1
+    pub column: usize,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {} column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a JSON5 document.
+pub fn from_str(s: &str) -> Result<Json5Value, ParseError> {
+    let mut parser = Parser {
+        src: s,
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    parser.skip_whitespace()?;
+    let value = parser.parse_value(0)?;
+    parser.skip_whitespace()?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(format!("unexpected character `{c}` after the value"))),
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    /// Byte offset of the next character
+    pos: usize,
+    line: usize,
+    /// Column of the next character, counted in characters
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.src[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        let is_line_end = match c {
+            '\n' | '\u{2028}' | '\u{2029}' => true,
+            '\r' => self.peek() != Some('\n'),
+            _ => false,
+        };
+        if is_line_end {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            offset: self.pos,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(format!("unexpected character `{c}`")),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() || c == '\u{feff}' => {
+                    self.bump();
+                }
+                Some('/') => match self.peek_second() {
+                    Some('/') => {
+                        while let Some(c) = self.bump() {
+                            if matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
+                                break;
+                            }
+                        }
+                    }
+                    Some('*') => {
+                        let start = self.error("unterminated comment");
+                        self.bump();
+                        self.bump();
+                        loop {
+                            match self.bump() {
+                                Some('*') if self.peek() == Some('/') => {
+                                    self.bump();
+                                    break;
+                                }
+                                Some(_) => {}
+                                None => return Err(start),
+                            }
+                        }
+                    }
+                    _ => return Ok(()),
+                },
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Json5Value, ParseError> {
+        let start = self.pos;
+        let line = self.line;
+        let column = self.column;
+        let node = match self.peek() {
+            Some('{') => self.parse_object(depth)?,
+            Some('[') => self.parse_array(depth)?,
+            Some(quote @ ('"' | '\'')) => Node::String(self.parse_string(quote)?),
+            Some(c) if c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'I' | 'N') => {
+                self.parse_number()?
+            }
+            Some(c) if is_identifier_start(c) => {
+                let word = self.parse_identifier()?;
+                match word.as_str() {
+                    "null" => Node::Null,
+                    "true" => Node::Boolean(true),
+                    "false" => Node::Boolean(false),
+                    _ => {
+                        return Err(ParseError {
+                            message: format!("unexpected identifier `{word}`"),
+                            offset: start,
+                            line,
+                            column,
+                        })
+                    }
+                }
+            }
+            _ => return Err(self.unexpected()),
+        };
+        Ok(Json5Value {
+            node,
+            position: Position {
+                start,
+                end: self.pos,
+                line,
+                column,
+            },
+        })
+    }
+
+    fn enter(&self, depth: usize) -> Result<usize, ParseError> {
+        if depth >= RECURSION_LIMIT {
+            Err(self.error("recursion limit exceeded"))
+        } else {
+            Ok(depth + 1)
+        }
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Result<Node, ParseError> {
+        let depth = self.enter(depth)?;
+        self.expect('[')?;
+        let mut values = vec![];
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some(']') {
+                self.bump();
+                return Ok(Node::Array(values));
+            }
+            values.push(self.parse_value(depth)?);
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => {}
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Result<Node, ParseError> {
+        let depth = self.enter(depth)?;
+        self.expect('{')?;
+        let mut entries: Vec<(String, Json5Value)> = vec![];
+        // the position of each key within `entries`
+        let mut indices: HashMap<String, usize> = HashMap::new();
+        loop {
+            self.skip_whitespace()?;
+            let key = match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    return Ok(Node::Object(entries));
+                }
+                Some(quote @ ('"' | '\'')) => self.parse_string(quote)?,
+                Some(c) if is_identifier_start(c) || c == '\\' => self.parse_identifier()?,
+                _ => return Err(self.unexpected()),
+            };
+            self.skip_whitespace()?;
+            self.expect(':')?;
+            self.skip_whitespace()?;
+            let value = self.parse_value(depth)?;
+            match indices.get(&key) {
+                Some(&index) => entries[index].1 = value,
+                None => {
+                    indices.insert(key.clone(), entries.len());
+                    entries.push((key, value));
+                }
+            }
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => {}
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Result<String, ParseError> {
+        let mut identifier = String::new();
+        loop {
+            let c = match self.peek() {
+                Some('\\') => {
+                    self.bump();
+                    if self.peek() != Some('u') {
+                        return Err(self.error("invalid escape sequence in identifier"));
+                    }
+                    self.bump();
+                    self.parse_unicode_escape()?
+                }
+                Some(c) if is_identifier_part(c) => {
+                    self.bump();
+                    c
+                }
+                _ => break,
+            };
+            let valid = if identifier.is_empty() {
+                is_identifier_start(c)
+            } else {
+                is_identifier_part(c)
+            };
+            if !valid {
+                return Err(self.error(format!("invalid character `{c}` in identifier")));
+            }
+            identifier.push(c);
+        }
+        if identifier.is_empty() {
+            return Err(self.unexpected());
+        }
+        Ok(identifier)
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<String, ParseError> {
+        let start = self.error("unterminated string");
+        self.expect(quote)?;
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(start),
+                Some(c) if c == quote => {
+                    self.bump();
+                    return Ok(s);
+                }
+                Some('\n' | '\r') => {
+                    return Err(self.error("unescaped line break in string"));
+                }
+                Some('\\') => {
+                    self.bump();
+                    if let Some(c) = self.parse_escape()? {
+                        s.push(c);
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    s.push(c);
+                }
+            }
+        }
+    }
+
+    /// Parse the escape sequence following a backslash. Escaped line breaks return `None`.
+    fn parse_escape(&mut self) -> Result<Option<char>, ParseError> {
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error("unexpected end of input")),
+        };
+        if c.is_ascii_digit()
+            && (c != '0' || self.peek_second().map_or(false, |c| c.is_ascii_digit()))
+        {
+            return Err(self.error("invalid escape sequence"));
+        }
+        self.bump();
+        Ok(Some(match c {
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{b}',
+            '0' => '\0',
+            'x' => {
+                let hi = self.parse_hex_digit()?;
+                let lo = self.parse_hex_digit()?;
+                char::from((hi << 4 | lo) as u8)
+            }
+            'u' => self.parse_unicode_escape()?,
+            '\n' | '\u{2028}' | '\u{2029}' => return Ok(None),
+            '\r' => {
+                if self.peek() == Some('\n') {
+                    self.bump();
+                }
+                return Ok(None);
+            }
+            c => c,
+        }))
+    }
+
+    fn parse_hex_digit(&mut self) -> Result<u32, ParseError> {
+        match self.peek().and_then(|c| c.to_digit(16)) {
+            Some(digit) => {
+                self.bump();
+                Ok(digit)
+            }
+            None => Err(self.error("invalid escape sequence")),
+        }
+    }
+
+    /// Parse the four hexadecimal digits of a `\u` escape, and the following low surrogate
+    /// if needed.
+    fn parse_unicode_escape(&mut self) -> Result<char, ParseError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            code = code << 4 | self.parse_hex_digit()?;
+        }
+        if (0xD800..0xDC00).contains(&code) {
+            if self.peek() == Some('\\') && self.peek_second() == Some('u') {
+                self.bump();
+                self.bump();
+                let mut low = 0;
+                for _ in 0..4 {
+                    low = low << 4 | self.parse_hex_digit()?;
+                }
+                if (0xDC00..0xE000).contains(&low) {
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                } else {
+                    return Err(self.error("invalid unicode surrogate pair"));
+                }
+            } else {
+                return Err(self.error("invalid unicode surrogate pair"));
+            }
+        }
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape sequence"))
+    }
+
+    fn parse_number(&mut self) -> Result<Node, ParseError> {
+        let start = self.pos;
+        let negative = match self.peek() {
+            Some('-') => {
+                self.bump();
+                true
+            }
+            Some('+') => {
+                self.bump();
+                false
+            }
+            _ => false,
+        };
+        let sign = if negative { -1.0 } else { 1.0 };
+        match self.peek() {
+            Some('I') => {
+                return self.parse_keyword("Infinity", Node::Float(sign * f64::INFINITY));
+            }
+            Some('N') => return self.parse_keyword("NaN", Node::Float(f64::NAN)),
+            Some('0') if matches!(self.peek_second(), Some('x' | 'X')) => {
+                self.bump();
+                self.bump();
+                let digits_start = self.pos;
+                while self.peek().map_or(false, |c| c.is_ascii_hexdigit()) {
+                    self.bump();
+                }
+                let digits = &self.src[digits_start..self.pos];
+                let x = u64::from_str_radix(digits, 16).map_err(|_| self.invalid_number(start))?;
+                return Ok(integer_node(negative, x));
+            }
+            _ => {}
+        }
+
+        let digits_start = self.pos;
+        let mut is_integer = true;
+        let integer_digits = self.skip_digits();
+        if integer_digits > 1 && self.src[digits_start..].starts_with('0') {
+            return Err(self.invalid_number(start));
+        }
+        let mut fraction_digits = 0;
+        if self.peek() == Some('.') {
+            is_integer = false;
+            self.bump();
+            fraction_digits = self.skip_digits();
+        }
+        if integer_digits == 0 && fraction_digits == 0 {
+            return Err(self.invalid_number(start));
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            is_integer = false;
+            self.bump();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.bump();
+            }
+            if self.skip_digits() == 0 {
+                return Err(self.invalid_number(start));
+            }
+        }
+        if self.peek().map_or(false, is_identifier_part) {
+            return Err(self.invalid_number(start));
+        }
+        let text = &self.src[digits_start..self.pos];
+        if is_integer {
+            if let Ok(x) = text.parse::<u64>() {
+                if !negative || x <= i64::MAX as u64 + 1 {
+                    return Ok(integer_node(negative, x));
+                }
+            }
+        }
+        let x: f64 = text.parse().map_err(|_| self.invalid_number(start))?;
+        Ok(Node::Float(sign * x))
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, node: Node) -> Result<Node, ParseError> {
+        let start = self.pos;
+        if self.parse_identifier()? == keyword {
+            Ok(node)
+        } else {
+            Err(self.invalid_number(start))
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let mut count = 0;
+        while self.peek().map_or(false, |c| c.is_ascii_digit()) {
+            self.bump();
+            count += 1;
+        }
+        count
+    }
+
+    fn invalid_number(&self, start: usize) -> ParseError {
+        let column = self.column - self.src[start..self.pos].chars().count();
+        ParseError {
+            message: format!("invalid number `{}`", &self.src[start..self.pos]),
+            offset: start,
+            line: self.line,
+            column,
+        }
+    }
+}
+
+fn integer_node(negative: bool, x: u64) -> Node {
+    if !negative || x == 0 {
+        Node::Integer(x)
+    } else if x <= i64::MAX as u64 {
+        Node::NegativeInteger(-(x as i64))
+    } else if x == i64::MAX as u64 + 1 {
+        Node::NegativeInteger(i64::MIN)
+    } else {
+        Node::Float(-(x as f64))
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '$' || c == '_'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '$' || c == '_' || c == '\u{200c}' || c == '\u{200d}'
+}
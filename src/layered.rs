@@ -0,0 +1,212 @@
+use crate::{DeserializeError, IntoValue, Map, Sequence, Span, Value, ValueKind, ValuePointerRef};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A value built by overlaying multiple values, called layers, on top of each other.
+///
+/// Maps are merged recursively, while any other value of a layer replaces the value
+/// found at the same location in the previous layers. In particular, sequences are not
+/// concatenated.
+///
+/// Each layer has a name, such as a file path or `"env"`. Every value of a `Layered`
+/// has a [`Span`] whose [`source`](Span::source) is the name of the layer it comes from,
+/// so that deserialization errors can report it along with their location, using
+/// [`ValuePointerRef::span`](crate::ValuePointerRef::span). The position of the value
+/// within its layer is kept when the layer provides it.
+///
+/// Because some inputs, such as environment variables, only contain strings, booleans and
+/// numbers can be deserialized from the strings of the layers which enable
+/// [`parses_string_scalars`](IntoValue::parses_string_scalars), such as
+/// [`ParseStrings`](crate::ParseStrings). The strings of the other layers are kept as strings.
+///
+/// Each layer is [validated](IntoValue::validate) when it is added, so that adding a layer
+/// which cannot be converted to a [`Value`] faithfully returns an error.
+///
+/// ## Example
+/// ```
+/// use jayson::{args, env::Env, Layered, OwnedMap, OwnedValue, StandardError};
+///
+/// let mut defaults = OwnedMap::new();
+/// defaults.insert("port", 7700u64);
+/// defaults.insert("db", [("url", "localhost")].into_iter().collect::<OwnedMap>());
+///
+/// let value = Layered::new()
+///     .layer::<StandardError>("defaults", OwnedValue::Map(defaults))?
+///     .layer::<StandardError>("env", Env::new().prefix("APP").read_vars([("APP__DB__URL", "db.internal")]))?
+///     .layer::<StandardError>("args", args::parse(["--port", "80"]).unwrap())?;
+/// # Ok::<(), StandardError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Layered {
+    node: Node,
+    span: Option<Span>,
+    /// Whether the layer of the value parses its strings into booleans and numbers
+    parse_string_scalars: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Null,
+    Boolean(bool),
+    Integer(u64),
+    NegativeInteger(i64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Sequence(Vec<Layered>),
+    Map(Vec<(String, Layered)>),
+}
+
+impl Default for Layered {
+    fn default() -> Self {
+        Self {
+            node: Node::Map(vec![]),
+            span: None,
+            parse_string_scalars: false,
+        }
+    }
+}
+
+impl Layered {
+    /// Create a `Layered` value without any layer. It is an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer on top of the previous ones.
+    ///
+    /// An error is returned if the layer is rejected by [`IntoValue::validate`].
+    pub fn layer<E: DeserializeError>(
+        mut self,
+        source: impl Into<Arc<str>>,
+        value: impl IntoValue,
+    ) -> Result<Self, E> {
+        self.push_layer(source, value)?;
+        Ok(self)
+    }
+
+    /// Add a layer on top of the previous ones.
+    ///
+    /// An error is returned if the layer is rejected by [`IntoValue::validate`], in which
+    /// case `self` is left unchanged.
+    pub fn push_layer<E: DeserializeError>(
+        &mut self,
+        source: impl Into<Arc<str>>,
+        value: impl IntoValue,
+    ) -> Result<(), E> {
+        let source = source.into();
+        let span = Self::layer_span(&source, &value);
+        value.validate(ValuePointerRef::Origin.with_span(Some(&span)))?;
+        let layer = Self::from_layer(&source, value);
+        self.merge(layer);
+        Ok(())
+    }
+
+    /// Return the span of a value of the layer named `source`.
+    fn layer_span<V: IntoValue>(source: &Arc<str>, value: &V) -> Span {
+        Span {
+            position: value.span().and_then(|span| span.position),
+            source: Some(source.clone()),
+        }
+    }
+
+    fn from_layer<V: IntoValue>(source: &Arc<str>, value: V) -> Self {
+        let span = Self::layer_span(source, &value);
+        let parse_string_scalars = value.parses_string_scalars();
+        let node = match value.into_value() {
+            Value::Null => Node::Null,
+            Value::Boolean(b) => Node::Boolean(b),
+            Value::Integer(x) => Node::Integer(x),
+            Value::NegativeInteger(x) => Node::NegativeInteger(x),
+            Value::Float(x) => Node::Float(x),
+            Value::String(x) => Node::String(x),
+            Value::Bytes(x) => Node::Bytes(x),
+            Value::Sequence(seq) => Node::Sequence(
+                seq.into_iter()
+                    .map(|x| Self::from_layer(source, x))
+                    .collect(),
+            ),
+            Value::Map(map) => Node::Map(
+                map.into_iter()
+                    .map(|(k, v)| (k, Self::from_layer(source, v)))
+                    .collect(),
+            ),
+        };
+        Self {
+            node,
+            span: Some(span),
+            parse_string_scalars,
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        match (&mut self.node, other.node) {
+            (Node::Map(entries), Node::Map(other_entries)) => {
+                let mut indices = entries
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (k, _))| (k.clone(), i))
+                    .collect::<HashMap<_, _>>();
+                for (key, value) in other_entries {
+                    match indices.get(&key) {
+                        Some(&i) => entries[i].1.merge(value),
+                        None => {
+                            indices.insert(key.clone(), entries.len());
+                            entries.push((key, value));
+                        }
+                    }
+                }
+                self.span = other.span;
+                self.parse_string_scalars = other.parse_string_scalars;
+            }
+            (_, node) => {
+                *self = Self {
+                    node,
+                    span: other.span,
+                    parse_string_scalars: other.parse_string_scalars,
+                }
+            }
+        }
+    }
+}
+
+impl IntoValue for Layered {
+    type Sequence = Vec<Layered>;
+    type Map = Vec<(String, Layered)>;
+
+    fn kind(&self) -> ValueKind {
+        match self.node {
+            Node::Null => ValueKind::Null,
+            Node::Boolean(_) => ValueKind::Boolean,
+            Node::Integer(_) => ValueKind::Integer,
+            Node::NegativeInteger(_) => ValueKind::NegativeInteger,
+            Node::Float(_) => ValueKind::Float,
+            Node::String(_) => ValueKind::String,
+            Node::Bytes(_) => ValueKind::Bytes,
+            Node::Sequence(_) => ValueKind::Sequence,
+            Node::Map(_) => ValueKind::Map,
+        }
+    }
+
+    fn into_value(self) -> Value<Self> {
+        match self.node {
+            Node::Null => Value::Null,
+            Node::Boolean(b) => Value::Boolean(b),
+            Node::Integer(x) => Value::Integer(x),
+            Node::NegativeInteger(x) => Value::NegativeInteger(x),
+            Node::Float(x) => Value::Float(x),
+            Node::String(x) => Value::String(x),
+            Node::Bytes(x) => Value::Bytes(x),
+            Node::Sequence(x) => Value::Sequence(x),
+            Node::Map(x) => Value::Map(x),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span.clone()
+    }
+
+    fn parses_string_scalars(&self) -> bool {
+        self.parse_string_scalars
+    }
+}
@@ -21,23 +21,109 @@ parsing at the same time.
 The main parts of Jayson are:
 1. [`DeserializeFromValue<E>`] is the main trait for deserialization
 2. [`IntoValue`] and [`Value`] describe the shape that the parsed serialized data must have
-3. [`DeserializeError`] is the trait that all deserialization errors must conform to
+3. [`DeserializeError`] is the trait that all deserialization errors must conform to, and
+   [`StandardError`] is a ready-made implementation of it
 4. [`MergeWithError<E>`] describes how to combine multiple errors together. It allows Jayson
-to return multiple deserialization errors at once.
+   to return multiple deserialization errors at once.
 5. [`ValuePointerRef`] and [`ValuePointer`] point to locations within the value. They are
-used to locate the origin of an error.
+   used to locate the origin of an error, and can be resolved against a [`ValueTree`] to
+   retrieve the offending value.
 6. [`deserialize`] is the main function to use to deserialize a value
-7. The [`DeserializeFromValue`](derive@DeserializeFromValue) derive proc macro
-
-If the feature `serde` is activated, then an implementation of [`IntoValue`] is provided
+7. [`OwnedValue`] is a format-agnostic value tree that can be deserialized by Jayson
+   and [`Layered`] overlays multiple values, such as configuration files and environment variables
+8. The [`DeserializeFromValue`](derive@DeserializeFromValue) derive proc macro
+9. [`SerializeIntoValue`] converts a type back into an [`OwnedValue`], and can be derived
+   with the same attributes as [`DeserializeFromValue`](derive@DeserializeFromValue)
+10. [`JsonSchema`] describes the values accepted by a type with a JSON Schema, which can
+    be obtained with [`schema_for`]
+
+The [`env`](crate::env) and [`args`](crate::args) modules provide a way to deserialize from
+environment variables and command-line arguments.
+
+If the feature `serde-json` is activated, then an implementation of [`IntoValue`] is provided
 for the type `serde_json::Value`. This allows using Jayson to deserialize from JSON.
+
+If the feature `toml` is activated, then an implementation of [`IntoValue`] is provided
+for the type `toml::Value`. TOML datetimes are deserialized as strings in the RFC 3339 format.
+
+If the feature `yaml` is activated, then an implementation of [`IntoValue`] is provided
+for the type `serde_yaml::Value`. Merge keys (`<<`) are resolved, tagged values such as
+`!Tag value` are deserialized as a map with a single key, `Tag`, and non-string keys are
+converted to strings. A map with two keys that are equal once converted, such as `1` and `"1"`,
+is rejected with [`ErrorKind::DuplicateKey`].
+
+If the features `msgpack` and `cbor` are activated, then an implementation of [`IntoValue`]
+is provided for the types `rmpv::Value` and `ciborium::Value` respectively. Binary data is
+deserialized as [`Value::Bytes`], map keys that are not strings are converted to strings,
+MessagePack extension values are deserialized as a map with the keys `type` and `data`, and CBOR
+tags are ignored. As with YAML, a map with two keys that are equal once converted is rejected.
+CBOR negative integers below `i64::MIN` are rejected with [`ErrorKind::IntegerOutOfRange`].
+
+If the feature `json5` is activated, then the [`json5`](crate::json5) module provides a
+parser for JSON5, a superset of JSON allowing comments, trailing commas, and unquoted keys.
+The parsed values know their position in the text, so that errors can point to a line and column.
+
+If the feature `diagnostics` is activated, then the [`diagnostics`](crate::diagnostics) module
+provides an error type accumulating all the deserialization errors, which can be rendered as a
+human-friendly report with a snippet of the JSON or JSON5 text they come from.
+
+If the feature `openapi` is activated, then the [`openapi`](crate::openapi) module provides a
+way to create the schemas of the components of an OpenAPI 3.1 document from the types
+implementing [`JsonSchema`].
+
+If the feature `query` is activated, then the [`query`](crate::query) module provides a
+way to deserialize from URL query strings and `application/x-www-form-urlencoded` data.
+
+If the feature `serde` is activated, then the [`serde`](crate::serde) module provides a way to
+deserialize from any format supported by serde, as well as the [`Serde`] wrapper to use
+Jayson types inside serde types. [`OwnedValue`] then also implements `serde::Serialize` and
+`serde::Deserialize`.
 */
 
 #![allow(clippy::len_without_is_empty)]
+pub mod args;
+mod bytes;
+#[cfg(feature = "ciborium")]
+mod cbor;
+mod describe;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod env;
+mod error_kind;
+#[cfg(any(feature = "serde_yaml", feature = "rmpv", feature = "ciborium"))]
+mod flow;
 mod impls;
+#[cfg(feature = "json5")]
+pub mod json5;
+mod layered;
+#[cfg(feature = "rmpv")]
+mod msgpack;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+mod owned_value;
+mod parse_strings;
+mod pointer;
+#[cfg(feature = "query")]
+pub mod query;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+pub use crate::serde::Serde;
+mod schema;
 #[cfg(feature = "serde_json")]
 mod serde_json;
-
+mod serialize;
+mod snapshot;
+mod standard_error;
+mod suggest;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "serde_yaml")]
+mod yaml;
+
+pub use bytes::{Base64, Bytes, Hex};
+pub use describe::{Description, FieldDescription, VariantDescription};
+pub use error_kind::{DecodeError, ErrorKind};
 /**
 It is possible to derive the `DeserializeFromValue` trait for structs and enums with named fields.
 The derive proc macro accept many arguments, explained below:
@@ -99,11 +185,115 @@ will parse the following:
 }
 ```
 
+The `rename_all` attribute accepts the same rules as serde: `lowercase`, `UPPERCASE`, `PascalCase`,
+`camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `"kebab-case"`, and `"SCREAMING-KEBAB-CASE"`.
+The rules can also be written as strings, which is required for the last two. Acronyms and digits are
+treated as separate words, so that `camelCase` turns `HTTPServer` into `httpServer`, and `value_2x` into
+`value2X`. A `rename_all` attribute read from serde's attributes, with `serde_compat`, converts names
+exactly as serde does instead. Two fields, or two variants, cannot have the same key or alias.
+
+A field can be ignored with the `skip` attribute. It is then not accepted as a key, and its value
+is always given by its `default` attribute, or by `Default::default()` otherwise:
+
+```rust
+use jayson::DeserializeFromValue;
+#[derive(DeserializeFromValue)]
+struct MyStruct {
+    x: bool,
+    #[jayson(skip)]
+    cache: Vec<u8>,
+}
+```
+
+Additional keys can be accepted for a field or an enum variant with the `alias` attribute:
+
+```rust
+use jayson::DeserializeFromValue;
+#[derive(DeserializeFromValue)]
+struct MyStruct {
+    #[jayson(alias = "colour")]
+    color: String,
+}
+```
+
+Types that already derive serde's `Deserialize` can reuse their serde attributes with the
+`serde_compat` container attribute. The `rename`, `rename_all`, `default`, `tag`, `deny_unknown_fields`,
+`skip`, and `alias` serde attributes are then read whenever the corresponding jayson attribute is
+missing. The serde attributes that only affect serialization are ignored, while the ones that jayson
+cannot honour, such as `flatten`, `untagged`, or `content`, cause a compilation error:
+
+```rust
+use jayson::DeserializeFromValue;
+# use serde::Deserialize;
+#[derive(Deserialize, DeserializeFromValue)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[jayson(serde_compat)]
+struct MyStruct {
+    my_field: bool,
+    #[serde(default, alias = "other")]
+    other_field: u8,
+}
+```
+
+The derived implementation also provides [`DeserializeFromValue::describe`], which returns the keys
+of the fields, whether they are required, and the tag and variants of enums, as a [`Description`].
 
 */
 pub use jayson_internal::DeserializeFromValue;
+pub use layered::Layered;
+pub use owned_value::{OwnedMap, OwnedValue};
+pub use parse_strings::ParseStrings;
+pub use pointer::{ParsePointerError, ValueTree};
+
+/**
+It is possible to derive the `SerializeIntoValue` trait for structs and enums with named fields.
+It accepts the same attributes as the [`DeserializeFromValue`](derive@DeserializeFromValue) derive
+macro, so that the serialized value can be deserialized back into the same type:
+
+```
+use jayson::{OwnedValue, SerializeIntoValue};
+
+#[derive(SerializeIntoValue)]
+#[jayson(tag = "type", rename_all = camelCase)]
+enum Shape {
+    Circle { center_x: f64, radius: f64 },
+    Unit,
+}
+
+let value = Shape::Circle { center_x: 1.0, radius: 2.0 }.serialize_into_value();
+assert!(matches!(value, OwnedValue::Map(_)));
+```
+
+Fields with the `skip` attribute are not serialized. The `from` attribute is not supported.
+*/
+pub use jayson_internal::SerializeIntoValue;
+
+/**
+It is possible to derive the `JsonSchema` trait for the types that derive
+[`DeserializeFromValue`](derive@DeserializeFromValue), with the same attributes. The schema then
+reflects the `rename`, `rename_all`, `default`, `deny_unknown_fields`, `tag`, `skip`, and `from`
+attributes:
+
+- the keys of the object are the renamed field names
+- the fields are required unless they have a `default` attribute or an optional type, such as `Option<T>`
+- `deny_unknown_fields` is translated to `"additionalProperties": false`
+- a tagged enum accepts one of the objects described by its variants, each with its tag value
+- a type with the `from` attribute has the schema of the type it is deserialized from
 
+The schema of a type without generic parameters is added to the `$defs` section of the root schema
+and is referenced by `$ref`, while the schema of a generic type is always inlined. The `///` doc
+comments of the type, its fields, and its variants are used as the `description` of their schemas.
+*/
+pub use jayson_internal::JsonSchema;
+pub use schema::{schema_for, JsonSchema, SchemaGenerator, SchemaSettings, JSON_SCHEMA_DIALECT};
+pub use serialize::SerializeIntoValue;
+pub use snapshot::ValueSnapshot;
+pub use standard_error::StandardError;
+pub use suggest::{did_you_mean, levenshtein_distance, unknown_message};
+
+use std::collections::VecDeque;
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 /// A location within a [`Value`].
 ///
@@ -117,13 +307,14 @@ use std::fmt::{Debug, Display};
 /// let pointer = ValuePointerRef::Origin;
 /// let pointer = pointer.push_key("a");
 /// let pointer = pointer.push_index(2);
-/// // now `pointer` points to "a".2
+/// // now `pointer` points to /a/2
 /// ```
 ///
 /// A `ValuePointerRef` is an immutable data structure, so it is cheap to extend and to copy.
 /// However, if you want to store it inside an owned type, you may want to convert it to a
 /// [`ValuePointer`] instead using [`self.to_owned()`](ValuePointerRef::to_owned).
 #[derive(Clone, Copy)]
+#[non_exhaustive]
 pub enum ValuePointerRef<'a> {
     Origin,
     Key {
@@ -134,6 +325,16 @@ pub enum ValuePointerRef<'a> {
         index: usize,
         prev: &'a ValuePointerRef<'a>,
     },
+    /// Same location as `prev`, annotated with the [`Span`] of the value found there.
+    Spanned {
+        span: &'a Span,
+        prev: &'a ValuePointerRef<'a>,
+    },
+    /// Same location as `prev`, whose strings can be parsed into booleans and numbers,
+    /// see [`IntoValue::parses_string_scalars`].
+    StringScalars {
+        prev: &'a ValuePointerRef<'a>,
+    },
 }
 impl<'a> Default for ValuePointerRef<'a> {
     fn default() -> Self {
@@ -151,6 +352,107 @@ impl<'a> ValuePointerRef<'a> {
     pub fn push_index(&'a self, index: usize) -> Self {
         Self::Index { index, prev: self }
     }
+    /// Annotate `self` with the span of the value it points to, if there is one.
+    #[must_use]
+    pub fn with_span(&'a self, span: Option<&'a Span>) -> Self {
+        match span {
+            Some(span) => Self::Spanned { span, prev: self },
+            None => *self,
+        }
+    }
+    /// Return the span of the value pointed to by `self`, if it is known.
+    ///
+    /// Spans are only available when the [`IntoValue`] backend provides them, see [`IntoValue::span`].
+    ///
+    /// Only the span given to the last call to [`with_span`](Self::with_span) is returned. The
+    /// span of a parent value is not inherited by its children, so the pointer returned by
+    /// `pointer.with_span(span).push_key("a")` has no span until it is annotated itself.
+    pub fn span(&self) -> Option<&'a Span> {
+        match self {
+            ValuePointerRef::Spanned { span, .. } => Some(span),
+            ValuePointerRef::StringScalars { prev } => prev.span(),
+            _ => None,
+        }
+    }
+    /// Mark that the strings of the value pointed to by `self` can be parsed into booleans
+    /// and numbers, if `parse` is true. See [`IntoValue::parses_string_scalars`].
+    #[must_use]
+    pub fn with_string_scalars(&'a self, parse: bool) -> Self {
+        if parse {
+            Self::StringScalars { prev: self }
+        } else {
+            *self
+        }
+    }
+    /// Whether the strings of the value pointed to by `self` can be parsed into booleans and
+    /// numbers, as marked by [`with_string_scalars`](Self::with_string_scalars).
+    ///
+    /// Like spans, the mark of a parent value is not inherited by its children.
+    pub fn parses_string_scalars(&self) -> bool {
+        match self {
+            ValuePointerRef::StringScalars { .. } => true,
+            ValuePointerRef::Spanned { prev, .. } => prev.parses_string_scalars(),
+            _ => false,
+        }
+    }
+    /// Return the pointer to the parent of the value pointed to by `self`, or `None` if
+    /// `self` points to the origin.
+    pub fn parent(&self) -> Option<ValuePointerRef<'a>> {
+        match self {
+            ValuePointerRef::Origin => None,
+            ValuePointerRef::Key { prev, .. } | ValuePointerRef::Index { prev, .. } => Some(**prev),
+            ValuePointerRef::Spanned { prev, .. } | ValuePointerRef::StringScalars { prev } => {
+                prev.parent()
+            }
+        }
+    }
+    /// Return the last component of `self`, or `None` if `self` points to the origin.
+    pub fn last(&self) -> Option<ValuePointerComponentRef<'a>> {
+        match self {
+            ValuePointerRef::Origin => None,
+            ValuePointerRef::Key { key, .. } => Some(ValuePointerComponentRef::Key(key)),
+            ValuePointerRef::Index { index, .. } => Some(ValuePointerComponentRef::Index(*index)),
+            ValuePointerRef::Spanned { prev, .. } | ValuePointerRef::StringScalars { prev } => {
+                prev.last()
+            }
+        }
+    }
+    /// Return the number of components of `self`, which is 0 for the origin.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut cur = *self;
+        while let Some(parent) = cur.parent() {
+            depth += 1;
+            cur = parent;
+        }
+        depth
+    }
+    /// Return an iterator over the components of `self`, from the origin to the pointed value.
+    ///
+    /// Iterating backwards, from the pointed value, does not allocate. Since a `ValuePointerRef`
+    /// only links to its parent, iterating forwards first collects the components left to return
+    /// into a vector, in a single walk. Both take a time linear in the [depth](Self::depth).
+    ///
+    /// ## Example
+    /// ```
+    /// use jayson::{ValuePointerComponentRef, ValuePointerRef};
+    ///
+    /// let origin = ValuePointerRef::Origin;
+    /// let a = origin.push_key("a");
+    /// let pointer = a.push_index(2);
+    /// let components = pointer.components().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     components,
+    ///     [ValuePointerComponentRef::Key("a"), ValuePointerComponentRef::Index(2)]
+    /// );
+    /// ```
+    pub fn components(&self) -> ValuePointerComponents<'a> {
+        ValuePointerComponents {
+            back: *self,
+            len: self.depth(),
+            collected: None,
+        }
+    }
     /// Convert `self` to its owned version
     pub fn to_owned(&self) -> ValuePointer {
         let mut cur = self;
@@ -166,6 +468,9 @@ impl<'a> ValuePointerRef<'a> {
                     components.push(ValuePointerComponent::Index(*index));
                     cur = prev;
                 }
+                ValuePointerRef::Spanned { prev, .. } | ValuePointerRef::StringScalars { prev } => {
+                    cur = prev;
+                }
             }
         }
         let components = components.into_iter().rev().collect();
@@ -180,24 +485,147 @@ pub enum ValuePointerComponent {
     Index(usize),
 }
 
+/// Part of a [`ValuePointerRef`], as returned by [`ValuePointerRef::components`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValuePointerComponentRef<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+impl<'a> ValuePointerComponentRef<'a> {
+    /// Convert `self` to its owned version
+    pub fn to_owned(&self) -> ValuePointerComponent {
+        match self {
+            ValuePointerComponentRef::Key(key) => ValuePointerComponent::Key(key.to_string()),
+            ValuePointerComponentRef::Index(index) => ValuePointerComponent::Index(*index),
+        }
+    }
+}
+
+/// An iterator over the components of a [`ValuePointerRef`], from the origin to the pointed value.
+///
+/// It is returned by [`ValuePointerRef::components`].
+#[derive(Clone)]
+pub struct ValuePointerComponents<'a> {
+    /// The pointer whose last component is the last component left to return
+    back: ValuePointerRef<'a>,
+    /// The number of components left to return
+    len: usize,
+    /// The components left to return, in order, once collected by `next`
+    collected: Option<VecDeque<ValuePointerComponentRef<'a>>>,
+}
+impl<'a> ValuePointerComponents<'a> {
+    /// Collect the components left to return, walking the pointer once
+    fn collect_components(&self) -> VecDeque<ValuePointerComponentRef<'a>> {
+        let mut components = VecDeque::with_capacity(self.len);
+        let mut cur = self.back;
+        while components.len() < self.len {
+            if let Some(component) = cur.last() {
+                components.push_front(component);
+            }
+            cur = cur.parent().unwrap_or(cur);
+        }
+        components
+    }
+}
+impl<'a> Iterator for ValuePointerComponents<'a> {
+    type Item = ValuePointerComponentRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.collected.is_none() {
+            self.collected = Some(self.collect_components());
+        }
+        self.len -= 1;
+        self.collected.as_mut().and_then(VecDeque::pop_front)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+impl<'a> DoubleEndedIterator for ValuePointerComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        if let Some(collected) = &mut self.collected {
+            return collected.pop_back();
+        }
+        let component = self.back.last();
+        self.back = self.back.parent().unwrap_or(self.back);
+        component
+    }
+}
+impl<'a> ExactSizeIterator for ValuePointerComponents<'a> {}
+
 /// The owned version of a [`ValuePointerRef`].
+///
+/// It is displayed as an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer,
+/// such as `/a/0`, and can also be written as a JSONPath, such as `$.a[0]`, with
+/// [`to_json_path`](ValuePointer::to_json_path). Both forms can be parsed with [`str::parse`].
+///
+/// Only the JSONPath form round-trips losslessly. A JSON Pointer does not distinguish keys from
+/// indices, so a key made of digits, such as `"2"`, is written as `/2` and parsed back as the
+/// index 2. The JSONPath form writes it as `$["2"]` instead.
+///
+/// ## Example
+/// ```
+/// use jayson::{ValuePointer, ValuePointerRef};
+///
+/// let origin = ValuePointerRef::Origin;
+/// let key = origin.push_key("weird key");
+/// let pointer = key.push_index(0).to_owned();
+/// assert_eq!(pointer.to_string(), "/weird key/0");
+/// assert_eq!(pointer.to_json_path(), r#"$["weird key"][0]"#);
+///
+/// assert_eq!("/weird key/0".parse::<ValuePointer>().unwrap(), pointer);
+/// assert_eq!(r#"$["weird key"][0]"#.parse::<ValuePointer>().unwrap(), pointer);
+/// ```
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ValuePointer {
     pub path: Vec<ValuePointerComponent>,
 }
-impl Display for ValuePointer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for component in self.path.iter().rev() {
-            match component {
-                ValuePointerComponent::Index(i) => {
-                    write!(f, ".{i}")?;
-                }
-                ValuePointerComponent::Key(s) => {
-                    write!(f, ".{s}")?;
-                }
-            }
+
+/// Where a value comes from: its position within the original serialized input, and the
+/// name of that input.
+///
+/// Backends which parse the serialized text themselves can attach a span to each
+/// value they produce by implementing [`IntoValue::span`]. The span of the value
+/// that caused a deserialization error is then available through
+/// [`ValuePointerRef::span`].
+///
+/// A span can also only name the input that the value comes from, without knowing the
+/// position of the value within it, which is useful when a value is built from multiple
+/// inputs, see [`Layered`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Position of the value within its input, if known
+    pub position: Option<Position>,
+    /// Name of the input containing the value, such as a file path, if known
+    pub source: Option<Arc<str>>,
+}
+
+/// The position of a value within the original serialized input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// Byte offset of the start of the value
+    pub start: usize,
+    /// Byte offset of the end of the value (exclusive)
+    pub end: usize,
+    /// Line of the start of the value, starting at 1
+    pub line: usize,
+    /// Column of the start of the value, in characters, starting at 1
+    pub column: usize,
+}
+
+impl From<Position> for Span {
+    fn from(position: Position) -> Self {
+        Self {
+            position: Some(position),
+            ..Self::default()
         }
-        Ok(())
     }
 }
 
@@ -210,6 +638,7 @@ pub enum ValueKind {
     NegativeInteger,
     Float,
     String,
+    Bytes,
     Sequence,
     Map,
 }
@@ -222,6 +651,7 @@ impl Display for ValueKind {
             ValueKind::NegativeInteger => write!(f, "NegativeInteger"),
             ValueKind::Float => write!(f, "Float"),
             ValueKind::String => write!(f, "String"),
+            ValueKind::Bytes => write!(f, "Bytes"),
             ValueKind::Sequence => write!(f, "Sequence"),
             ValueKind::Map => write!(f, "Map"),
         }
@@ -247,6 +677,11 @@ pub enum Value<V: IntoValue> {
     NegativeInteger(i64),
     Float(f64),
     String(String),
+    /// Binary data, for the formats that support it.
+    ///
+    /// The implementation of [`DeserializeFromValue`] for `Vec<T>` also accepts it, as if it
+    /// were a sequence of integers.
+    Bytes(Vec<u8>),
     Sequence(V::Sequence),
     Map(V::Map),
 }
@@ -259,6 +694,7 @@ impl<V: IntoValue> Value<V> {
             Value::NegativeInteger(_) => ValueKind::NegativeInteger,
             Value::Float(_) => ValueKind::Float,
             Value::String(_) => ValueKind::String,
+            Value::Bytes(_) => ValueKind::Bytes,
             Value::Sequence(_) => ValueKind::Sequence,
             Value::Map(_) => ValueKind::Map,
         }
@@ -270,8 +706,46 @@ pub trait IntoValue: Sized {
     type Sequence: Sequence<Value = Self>;
     type Map: Map<Value = Self>;
 
+    /// Whether the implementations of [`DeserializeFromValue`] for booleans, integers, and floats
+    /// should also accept strings representing these values, such as `"true"` or `"12"`.
+    ///
+    /// A string is only parsed by the implementation which expects a boolean, an integer, or a
+    /// float, and an error about the original string is returned if it cannot be parsed. The
+    /// implementations for `Vec<T>`, `HashSet<T>` and `BTreeSet<T>` also accept a single string
+    /// as a sequence of one element.
+    ///
+    /// This is useful for formats which only contain strings, such as query strings. See
+    /// [`ParseStrings`] to enable it for any type implementing `IntoValue`. It can also be
+    /// enabled for a single value with [`parses_string_scalars`](IntoValue::parses_string_scalars).
+    const PARSE_STRING_SCALARS: bool = false;
+
     fn kind(&self) -> ValueKind;
     fn into_value(self) -> Value<Self>;
+    /// Where `self` comes from, if known.
+    fn span(&self) -> Option<Span> {
+        None
+    }
+    /// Whether the strings of `self` can be parsed into booleans and numbers, as described in
+    /// [`PARSE_STRING_SCALARS`](IntoValue::PARSE_STRING_SCALARS), which is the default.
+    ///
+    /// It allows a type to enable it only for some of its values, as [`Layered`] does for the
+    /// values of the layers which need it. The implementations of [`DeserializeFromValue`]
+    /// for sequences, maps, and tuples pass it on to their elements with
+    /// [`ValuePointerRef::with_string_scalars`].
+    fn parses_string_scalars(&self) -> bool {
+        Self::PARSE_STRING_SCALARS
+    }
+    /// Check that `self` and its descendants can be converted to a [`Value`] without losing
+    /// information, and report the first offending value otherwise.
+    ///
+    /// It is called by [`deserialize`] before deserializing the value. Formats whose map keys
+    /// are not all strings, or whose integers may not fit in a [`Value`], use it to reject
+    /// the values that [`into_value`](IntoValue::into_value) cannot represent faithfully.
+    /// The default implementation accepts every value.
+    fn validate<E: DeserializeError>(&self, location: ValuePointerRef) -> Result<(), E> {
+        let _ = location;
+        Ok(())
+    }
 }
 
 /// A sequence of values conforming to [`IntoValue`].
@@ -306,6 +780,12 @@ pub trait DeserializeFromValue<E: DeserializeError>: Sized {
     fn default() -> Option<Self> {
         None
     }
+    /// Describe the values accepted by `Self`.
+    ///
+    /// The default implementation returns [`Description::Unknown`].
+    fn describe() -> Description {
+        Description::Unknown
+    }
 }
 
 /// Deserialize the given value.
@@ -320,7 +800,15 @@ where
     Val: IntoValue,
     E: DeserializeError,
 {
-    Ret::deserialize_from_value(value.into_value(), ValuePointerRef::Origin)
+    value.validate::<E>(ValuePointerRef::Origin)?;
+    let span = value.span();
+    let parse = value.parses_string_scalars();
+    Ret::deserialize_from_value(
+        value.into_value(),
+        ValuePointerRef::Origin
+            .with_string_scalars(parse)
+            .with_span(span.as_ref()),
+    )
 }
 
 /// A trait which describes how to combine two errors together.
@@ -384,6 +872,22 @@ pub trait DeserializeError: Sized + MergeWithError<Self> {
         accepted: &[ValueKind],
         location: ValuePointerRef,
     ) -> Result<Self, Self>;
+    /// Create a new error due to an unexpected value kind, with a [snapshot](ValueSnapshot)
+    /// of the actual value.
+    ///
+    /// This makes messages such as ``expected an integer, found string "abc"`` possible.
+    /// The default implementation calls [`incorrect_value_kind`](DeserializeError::incorrect_value_kind)
+    /// with the kind of the snapshot.
+    ///
+    /// Return `Ok` to continue deserializing or `Err` to fail early.
+    fn incorrect_value(
+        self_: Option<Self>,
+        actual: ValueSnapshot,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Self::incorrect_value_kind(self_, actual.kind(), accepted, location)
+    }
     /// Create a new error due to a missing key.
     ///
     /// Return `Ok` to continue deserializing or `Err` to fail early.
@@ -394,6 +898,9 @@ pub trait DeserializeError: Sized + MergeWithError<Self> {
     ) -> Result<Self, Self>;
     /// Create a new error due to finding an unknown key.
     ///
+    /// The [`did_you_mean`] and [`unknown_message`] functions can be used to suggest
+    /// one of the `accepted` keys.
+    ///
     /// Return `Ok` to continue deserializing or `Err` to fail early.
     fn unknown_key(
         self_: Option<Self>,
@@ -401,6 +908,42 @@ pub trait DeserializeError: Sized + MergeWithError<Self> {
         accepted: &[&str],
         location: ValuePointerRef,
     ) -> Result<Self, Self>;
+    /// Create a new error due to finding an unknown value for the tag of an enum.
+    ///
+    /// `found` is the value of the tag, `accepted` contains the tag values of all the variants,
+    /// and `location` points to the tag.
+    ///
+    /// The default implementation calls [`unexpected`](DeserializeError::unexpected) with the
+    /// message given by [`unknown_message`].
+    ///
+    /// Return `Ok` to continue deserializing or `Err` to fail early.
+    fn unknown_variant(
+        self_: Option<Self>,
+        found: &str,
+        accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Self::unexpected(
+            self_,
+            &unknown_message("tag value", found, accepted),
+            location,
+        )
+    }
+    /// Create a new error due to a value of an accepted kind that is nonetheless invalid,
+    /// such as an integer that is out of range.
+    ///
+    /// The default implementation calls [`unexpected`](DeserializeError::unexpected) with the
+    /// English message given by the [`Display`] implementation of [`ErrorKind`]. It can be
+    /// overridden to render the error kind and its parameters differently, such as in another language.
+    ///
+    /// Return `Ok` to continue deserializing or `Err` to fail early.
+    fn invalid_value(
+        self_: Option<Self>,
+        kind: ErrorKind,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Self::unexpected(self_, &kind.to_string(), location)
+    }
     /// Create a new error with the custom message.
     ///
     /// Return `Ok` to continue deserializing or `Err` to fail early.
@@ -0,0 +1,114 @@
+use crate::flow::{find_key, key_to_string, string_keys, write_bytes, Flow, FlowValue};
+use crate::{DeserializeError, IntoValue, Value, ValueKind, ValuePointerRef, ValueTree};
+use rmpv::Value as MValue;
+
+impl IntoValue for MValue {
+    type Sequence = Vec<MValue>;
+    type Map = Vec<(String, MValue)>;
+
+    fn into_value(self) -> Value<Self> {
+        match self {
+            MValue::Nil => Value::Null,
+            MValue::Boolean(b) => Value::Boolean(b),
+            MValue::Integer(n) => match (n.as_u64(), n.as_i64()) {
+                (Some(n), _) => Value::Integer(n),
+                (None, Some(n)) => Value::NegativeInteger(n),
+                // rmpv integers are always either a u64 or an i64
+                (None, None) => Value::Null,
+            },
+            MValue::F32(x) => Value::Float(x as f64),
+            MValue::F64(x) => Value::Float(x),
+            MValue::String(x) => Value::String(utf8_string(x)),
+            MValue::Binary(x) => Value::Bytes(x),
+            MValue::Array(x) => Value::Sequence(x),
+            MValue::Map(x) => {
+                Value::Map(x.into_iter().map(|(k, v)| (key_to_string(&k), v)).collect())
+            }
+            MValue::Ext(ty, data) => Value::Map(vec![
+                ("type".to_owned(), MValue::from(ty)),
+                ("data".to_owned(), MValue::Binary(data)),
+            ]),
+        }
+    }
+
+    fn kind(&self) -> ValueKind {
+        match self {
+            MValue::Nil => ValueKind::Null,
+            MValue::Boolean(_) => ValueKind::Boolean,
+            MValue::Integer(n) if n.is_u64() => ValueKind::Integer,
+            MValue::Integer(_) => ValueKind::NegativeInteger,
+            MValue::F32(_) | MValue::F64(_) => ValueKind::Float,
+            MValue::String(_) => ValueKind::String,
+            MValue::Binary(_) => ValueKind::Bytes,
+            MValue::Array(_) => ValueKind::Sequence,
+            MValue::Map(_) | MValue::Ext(_, _) => ValueKind::Map,
+        }
+    }
+
+    /// Reject the maps with two keys that are equal once converted to strings,
+    /// such as `1` and `"1"`.
+    fn validate<E: DeserializeError>(&self, location: ValuePointerRef) -> Result<(), E> {
+        match self {
+            MValue::Array(seq) => {
+                for (i, value) in seq.iter().enumerate() {
+                    value.validate(location.push_index(i))?;
+                }
+                Ok(())
+            }
+            MValue::Map(map) => {
+                for (key, value) in string_keys(map.iter().map(|(k, v)| (k, v)), location)? {
+                    value.validate(location.push_key(&key))?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Convert a MessagePack string to a `String`, replacing invalid UTF-8 sequences.
+fn utf8_string(s: rmpv::Utf8String) -> String {
+    String::from_utf8(s.into_bytes())
+        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
+/// Keys are compared once converted to strings, as when deserializing. The `type` and `data`
+/// keys of extension values cannot be resolved, since they are not stored as values.
+impl ValueTree for MValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            MValue::Map(map) => find_key(map.iter().map(|(k, v)| (k, v)), key),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            MValue::Array(seq) => seq.get(index),
+            _ => None,
+        }
+    }
+}
+
+/// Binary data is written as a sequence of integers, and extension values as
+/// `{type: 1, data: [0]}`.
+impl FlowValue for MValue {
+    fn flow(&self) -> Flow<'_, Self> {
+        match self {
+            MValue::Nil => Flow::Text("null".into()),
+            MValue::Boolean(b) => Flow::Text(b.to_string().into()),
+            MValue::Integer(n) => Flow::Text(n.to_string().into()),
+            MValue::F32(x) => Flow::Text(x.to_string().into()),
+            MValue::F64(x) => Flow::Text(x.to_string().into()),
+            MValue::String(x) => Flow::Text(String::from_utf8_lossy(x.as_bytes())),
+            MValue::Binary(bytes) => Flow::Bytes(bytes),
+            MValue::Array(seq) => Flow::Sequence(seq),
+            MValue::Map(map) => Flow::Map(map.iter().map(|(k, v)| (k, v)).collect()),
+            MValue::Ext(ty, data) => {
+                let mut s = format!("{{type: {ty}, data: ");
+                write_bytes(&mut s, data);
+                s.push('}');
+                Flow::Text(s.into())
+            }
+        }
+    }
+}
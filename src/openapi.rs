@@ -0,0 +1,86 @@
+/*!
+Create the schemas of the components of an OpenAPI 3.1 document.
+
+The schemas are created by the implementations of [`JsonSchema`], which can be derived, with
+the [OpenAPI settings](SchemaSettings::openapi3):
+
+- named types are referenced with `#/components/schemas/Name`
+- tagged enums have a `discriminator` object mapping each tag value to the schema of its
+  variant, which is added to the components with the name `Enum.Variant`
+- the `///` doc comments of the derived types, fields, and variants are used as descriptions
+
+## Example
+```
+use jayson::{openapi::Components, JsonSchema};
+
+/// A geometric shape
+#[derive(JsonSchema)]
+#[jayson(tag = "kind", rename_all = lowercase)]
+enum Shape {
+    /// A circle centered on the origin
+    Circle { radius: f64 },
+    Point,
+}
+
+let mut components = Components::new();
+let reference = components.add::<Shape>();
+let components = serde_json::Value::from(components.into_value());
+
+assert_eq!(serde_json::Value::from(reference)["$ref"], "#/components/schemas/Shape");
+assert_eq!(components["schemas"]["Shape"]["discriminator"]["propertyName"], "kind");
+assert_eq!(
+    components["schemas"]["Shape"]["discriminator"]["mapping"]["circle"],
+    "#/components/schemas/Shape.Circle"
+);
+```
+*/
+
+use crate::{JsonSchema, OwnedMap, OwnedValue, SchemaGenerator, SchemaSettings};
+
+/// The schemas of the components of an OpenAPI document.
+#[derive(Clone, Debug)]
+pub struct Components {
+    gen: SchemaGenerator,
+}
+
+impl Default for Components {
+    fn default() -> Self {
+        Self {
+            gen: SchemaGenerator::with_settings(SchemaSettings::openapi3()),
+        }
+    }
+}
+
+impl Components {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the schema of `T`, and of the named types it references, to the components.
+    ///
+    /// The returned schema can be used to refer to `T` elsewhere in the OpenAPI document. It is
+    /// a `$ref` for named types, and the schema of `T` for the types without a
+    /// [`schema_name`](JsonSchema::schema_name), which are not added to the components.
+    pub fn add<T: JsonSchema + ?Sized>(&mut self) -> OwnedValue {
+        self.gen.subschema_for::<T>()
+    }
+
+    /// The schemas added so far, by name.
+    pub fn schemas(&self) -> &OwnedMap {
+        self.gen.definitions()
+    }
+
+    /// Return the components object, which contains the `schemas` map.
+    pub fn into_value(self) -> OwnedValue {
+        let mut components = OwnedMap::new();
+        components.insert("schemas", self.gen.into_definitions());
+        OwnedValue::Map(components)
+    }
+}
+
+/// Return the components object containing the schema of `T` and of the named types it references.
+pub fn components_for<T: JsonSchema + ?Sized>() -> OwnedValue {
+    let mut components = Components::new();
+    components.add::<T>();
+    components.into_value()
+}
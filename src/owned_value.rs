@@ -0,0 +1,318 @@
+use crate::{
+    DeserializeError, DeserializeFromValue, IntoValue, Map, Sequence, Value, ValueKind,
+    ValuePointerRef,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+};
+
+/// A generic, owned value tree readable by Jayson.
+///
+/// It can be built by hand, by a parser, or from any other [`IntoValue`] type with
+/// [`OwnedValue::from_value`]. This makes it useful for tests, for configuration values
+/// that are created programmatically, and as a common intermediate between formats.
+///
+/// ## Example
+/// ```
+/// use jayson::{OwnedMap, OwnedValue};
+///
+/// let mut map = OwnedMap::new();
+/// map.insert("name", "jayson");
+/// map.insert("tags", vec!["json", "errors"]);
+/// map.insert("stars", 10u64);
+/// let value = OwnedValue::Map(map);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum OwnedValue {
+    Null,
+    Boolean(bool),
+    Integer(u64),
+    NegativeInteger(i64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Sequence(Vec<OwnedValue>),
+    Map(OwnedMap),
+}
+
+impl OwnedValue {
+    /// Copy the content of the given value into an `OwnedValue`.
+    ///
+    /// The value is first checked with [`IntoValue::validate`], whose error is returned if
+    /// the value cannot be represented faithfully.
+    pub fn from_value<V: IntoValue, E: DeserializeError>(value: V) -> Result<Self, E> {
+        crate::deserialize(value)
+    }
+
+    /// Copy the content of the given value, which was already validated, into an `OwnedValue`.
+    pub(crate) fn from_validated_value<V: IntoValue>(value: Value<V>) -> Self {
+        match value {
+            Value::Null => OwnedValue::Null,
+            Value::Boolean(b) => OwnedValue::Boolean(b),
+            Value::Integer(x) => OwnedValue::Integer(x),
+            Value::NegativeInteger(x) => OwnedValue::NegativeInteger(x),
+            Value::Float(x) => OwnedValue::Float(x),
+            Value::String(x) => OwnedValue::String(x),
+            Value::Bytes(x) => OwnedValue::Bytes(x),
+            Value::Sequence(seq) => OwnedValue::Sequence(
+                seq.into_iter()
+                    .map(|x| Self::from_validated_value(x.into_value()))
+                    .collect(),
+            ),
+            Value::Map(map) => OwnedValue::Map(
+                map.into_iter()
+                    .map(|(k, v)| (k, Self::from_validated_value(v.into_value())))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// A map of [`OwnedValue`] which preserves the insertion order of its keys.
+///
+/// Inserting a key that already exists replaces its value but keeps its original position.
+/// Looking up, inserting, and removing a key take constant time on average.
+#[derive(Clone, Default)]
+pub struct OwnedMap {
+    /// The entries in insertion order. Removed entries leave a `None` behind until the
+    /// entries are compacted, so that the positions stored in `indices` stay valid.
+    entries: Vec<Option<(String, OwnedValue)>>,
+    /// The position of each key within `entries`
+    indices: HashMap<String, usize>,
+}
+
+impl OwnedMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+    pub fn get(&self, key: &str) -> Option<&OwnedValue> {
+        let index = *self.indices.get(key)?;
+        self.entries[index].as_ref().map(|(_, v)| v)
+    }
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut OwnedValue> {
+        let index = *self.indices.get(key)?;
+        self.entries[index].as_mut().map(|(_, v)| v)
+    }
+    /// Insert the value at the given key, returning the previous value at that key, if any.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<OwnedValue>,
+    ) -> Option<OwnedValue> {
+        let key = key.into();
+        let value = value.into();
+        match self.get_mut(&key) {
+            Some(existing) => Some(std::mem::replace(existing, value)),
+            None => {
+                self.indices.insert(key.clone(), self.entries.len());
+                self.entries.push(Some((key, value)));
+                None
+            }
+        }
+    }
+    /// Remove the value at the given key, preserving the order of the other keys.
+    pub fn remove(&mut self, key: &str) -> Option<OwnedValue> {
+        let index = self.indices.remove(key)?;
+        let (_, value) = self.entries[index].take()?;
+        // compact the entries once most of them have been removed, so that
+        // the cost of compacting is amortized over the removals
+        if self.entries.len() > 2 * self.indices.len() + 8 {
+            self.entries.retain(Option::is_some);
+            for (index, (key, _)) in self.entries.iter().flatten().enumerate() {
+                *self.indices.get_mut(key.as_str()).unwrap() = index;
+            }
+        }
+        Some(value)
+    }
+    /// Iterate over the entries of the map, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &OwnedValue)> {
+        self.entries.iter().flatten().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+impl PartialEq for OwnedMap {
+    /// Two maps are equal if they have the same entries in the same order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Debug for OwnedMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedMap")
+            .field("entries", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OwnedMap
+where
+    K: Into<String>,
+    V: Into<OwnedValue>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = OwnedMap::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl IntoIterator for OwnedMap {
+    type Item = (String, OwnedValue);
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<(String, OwnedValue)>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.entries).flatten()
+    }
+}
+
+impl Map for OwnedMap {
+    type Value = OwnedValue;
+    type Iter = <Self as IntoIterator>::IntoIter;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn remove(&mut self, key: &str) -> Option<Self::Value> {
+        self.remove(key)
+    }
+    fn into_iter(self) -> Self::Iter {
+        <Self as IntoIterator>::into_iter(self)
+    }
+}
+
+impl IntoValue for OwnedValue {
+    type Sequence = Vec<OwnedValue>;
+    type Map = OwnedMap;
+
+    fn kind(&self) -> ValueKind {
+        match self {
+            OwnedValue::Null => ValueKind::Null,
+            OwnedValue::Boolean(_) => ValueKind::Boolean,
+            OwnedValue::Integer(_) => ValueKind::Integer,
+            OwnedValue::NegativeInteger(_) => ValueKind::NegativeInteger,
+            OwnedValue::Float(_) => ValueKind::Float,
+            OwnedValue::String(_) => ValueKind::String,
+            OwnedValue::Bytes(_) => ValueKind::Bytes,
+            OwnedValue::Sequence(_) => ValueKind::Sequence,
+            OwnedValue::Map(_) => ValueKind::Map,
+        }
+    }
+
+    fn into_value(self) -> Value<Self> {
+        match self {
+            OwnedValue::Null => Value::Null,
+            OwnedValue::Boolean(b) => Value::Boolean(b),
+            OwnedValue::Integer(x) => Value::Integer(x),
+            OwnedValue::NegativeInteger(x) => Value::NegativeInteger(x),
+            OwnedValue::Float(x) => Value::Float(x),
+            OwnedValue::String(x) => Value::String(x),
+            OwnedValue::Bytes(x) => Value::Bytes(x),
+            OwnedValue::Sequence(x) => Value::Sequence(x),
+            OwnedValue::Map(x) => Value::Map(x),
+        }
+    }
+}
+
+impl<E: DeserializeError> DeserializeFromValue<E> for OwnedValue {
+    fn deserialize_from_value<V: IntoValue>(
+        value: Value<V>,
+        _location: ValuePointerRef,
+    ) -> Result<Self, E> {
+        Ok(Self::from_validated_value(value))
+    }
+}
+
+impl From<bool> for OwnedValue {
+    fn from(b: bool) -> Self {
+        OwnedValue::Boolean(b)
+    }
+}
+
+macro_rules! from_unsigned_integer {
+    ($t:ty) => {
+        impl From<$t> for OwnedValue {
+            fn from(x: $t) -> Self {
+                OwnedValue::Integer(x as u64)
+            }
+        }
+    };
+}
+from_unsigned_integer!(u8);
+from_unsigned_integer!(u16);
+from_unsigned_integer!(u32);
+from_unsigned_integer!(u64);
+from_unsigned_integer!(usize);
+
+macro_rules! from_signed_integer {
+    ($t:ty) => {
+        impl From<$t> for OwnedValue {
+            fn from(x: $t) -> Self {
+                if x < 0 {
+                    OwnedValue::NegativeInteger(x as i64)
+                } else {
+                    OwnedValue::Integer(x as u64)
+                }
+            }
+        }
+    };
+}
+from_signed_integer!(i8);
+from_signed_integer!(i16);
+from_signed_integer!(i32);
+from_signed_integer!(i64);
+from_signed_integer!(isize);
+
+impl From<f32> for OwnedValue {
+    fn from(x: f32) -> Self {
+        OwnedValue::Float(x as f64)
+    }
+}
+impl From<f64> for OwnedValue {
+    fn from(x: f64) -> Self {
+        OwnedValue::Float(x)
+    }
+}
+impl From<String> for OwnedValue {
+    fn from(x: String) -> Self {
+        OwnedValue::String(x)
+    }
+}
+impl From<&str> for OwnedValue {
+    fn from(x: &str) -> Self {
+        OwnedValue::String(x.to_owned())
+    }
+}
+impl<T: Into<OwnedValue>> From<Vec<T>> for OwnedValue {
+    fn from(x: Vec<T>) -> Self {
+        OwnedValue::Sequence(x.into_iter().map(Into::into).collect())
+    }
+}
+impl<T: Into<OwnedValue>> From<Option<T>> for OwnedValue {
+    fn from(x: Option<T>) -> Self {
+        match x {
+            Some(x) => x.into(),
+            None => OwnedValue::Null,
+        }
+    }
+}
+impl From<OwnedMap> for OwnedValue {
+    fn from(x: OwnedMap) -> Self {
+        OwnedValue::Map(x)
+    }
+}
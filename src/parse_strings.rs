@@ -0,0 +1,83 @@
+use crate::{DeserializeError, IntoValue, Map, Sequence, Span, Value, ValueKind, ValuePointerRef};
+
+/// A wrapper around any [`IntoValue`] type which enables
+/// [`PARSE_STRING_SCALARS`](IntoValue::PARSE_STRING_SCALARS).
+///
+/// Booleans, integers, and floats can then be deserialized from strings such as
+/// `"true"`, `"-2"`, or `"1.5"`. The errors returned when deserializing these strings
+/// are the same as the ones returned when deserializing the values they represent.
+///
+/// ## Example
+/// ```
+/// use jayson::{OwnedValue, ParseStrings, StandardError};
+///
+/// let value = ParseStrings(OwnedValue::from(vec!["1", "2"]));
+/// let numbers = jayson::deserialize::<Vec<u8>, _, StandardError>(value).unwrap();
+/// assert_eq!(numbers, [1, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseStrings<V>(pub V);
+
+impl<V: IntoValue> IntoValue for ParseStrings<V> {
+    type Sequence = ParseStrings<V::Sequence>;
+    type Map = ParseStrings<V::Map>;
+
+    const PARSE_STRING_SCALARS: bool = true;
+
+    fn kind(&self) -> ValueKind {
+        self.0.kind()
+    }
+
+    fn into_value(self) -> Value<Self> {
+        match self.0.into_value() {
+            Value::Null => Value::Null,
+            Value::Boolean(b) => Value::Boolean(b),
+            Value::Integer(x) => Value::Integer(x),
+            Value::NegativeInteger(x) => Value::NegativeInteger(x),
+            Value::Float(x) => Value::Float(x),
+            Value::String(x) => Value::String(x),
+            Value::Bytes(x) => Value::Bytes(x),
+            Value::Sequence(x) => Value::Sequence(ParseStrings(x)),
+            Value::Map(x) => Value::Map(ParseStrings(x)),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.0.span()
+    }
+
+    fn validate<E: DeserializeError>(&self, location: ValuePointerRef) -> Result<(), E> {
+        self.0.validate(location)
+    }
+}
+
+impl<S: Sequence> Sequence for ParseStrings<S> {
+    type Value = ParseStrings<S::Value>;
+    type Iter = std::iter::Map<S::Iter, fn(S::Value) -> ParseStrings<S::Value>>;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self.0.into_iter().map(ParseStrings)
+    }
+}
+
+impl<M: Map> Map for ParseStrings<M> {
+    type Value = ParseStrings<M::Value>;
+    #[allow(clippy::type_complexity)]
+    type Iter = std::iter::Map<M::Iter, fn((String, M::Value)) -> (String, ParseStrings<M::Value>)>;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Self::Value> {
+        self.0.remove(key).map(ParseStrings)
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self.0.into_iter().map(|(k, v)| (k, ParseStrings(v)))
+    }
+}
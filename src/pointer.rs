@@ -0,0 +1,387 @@
+use crate::{OwnedValue, ValuePointer, ValuePointerComponent};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// An error returned when parsing a [`ValuePointer`] from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsePointerError {
+    /// Description of the error, such as ``expected `]` ``
+    pub message: String,
+    /// Byte offset of the error
+    pub offset: usize,
+}
+
+impl Display for ParsePointerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for ParsePointerError {}
+
+fn error(message: impl Into<String>, offset: usize) -> ParsePointerError {
+    ParsePointerError {
+        message: message.into(),
+        offset,
+    }
+}
+
+/// Write `self` as an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer,
+/// such as `/a/0`, where `~` and `/` within keys are escaped as `~0` and `~1`.
+///
+/// The pointer to the origin is the empty string. Keys made of digits are written as
+/// indices, so use [`ValuePointer::to_json_path`] when the pointer must be parsed back exactly.
+impl Display for ValuePointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for component in &self.path {
+            match component {
+                ValuePointerComponent::Index(i) => write!(f, "/{i}")?,
+                ValuePointerComponent::Key(key) => {
+                    write!(f, "/{}", key.replace('~', "~0").replace('/', "~1"))?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a JSON Pointer, such as `/a/0`, or a JSONPath, such as `$.a[0]`.
+///
+/// Strings starting with `$` are parsed with [`ValuePointer::from_json_path`] and the other
+/// ones with [`ValuePointer::from_json_pointer`].
+impl FromStr for ValuePointer {
+    type Err = ParsePointerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with('$') {
+            Self::from_json_path(s)
+        } else {
+            Self::from_json_pointer(s)
+        }
+    }
+}
+
+impl ValuePointer {
+    /// Return `self` as an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer,
+    /// such as `/a/0`. This is the same as its [`Display`] implementation.
+    pub fn to_json_pointer(&self) -> String {
+        self.to_string()
+    }
+
+    /// Return `self` as a JSONPath, such as `$.a[0]`.
+    ///
+    /// Keys which are not identifiers are written with the bracket notation and quoted,
+    /// such as `$["weird key"]`. Unlike the JSON Pointer, the result is always parsed back
+    /// to `self` by [`ValuePointer::from_json_path`].
+    pub fn to_json_path(&self) -> String {
+        let mut path = String::from("$");
+        for component in &self.path {
+            match component {
+                ValuePointerComponent::Index(i) => {
+                    let _ = write!(path, "[{i}]");
+                }
+                ValuePointerComponent::Key(key) if is_identifier(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                ValuePointerComponent::Key(key) => {
+                    path.push('[');
+                    push_quoted(&mut path, key);
+                    path.push(']');
+                }
+            }
+        }
+        path
+    }
+
+    /// Parse an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer, such as `/a/0`.
+    ///
+    /// A JSON Pointer does not distinguish between keys and indices, so the reference tokens
+    /// which are non-negative integers without leading zeros are parsed as indices, even if
+    /// they were keys when the pointer was written.
+    ///
+    /// ## Example
+    /// ```
+    /// use jayson::{ValuePointer, ValuePointerComponent};
+    ///
+    /// let pointer = ValuePointer::from_json_pointer("/a~1b/0").unwrap();
+    /// assert_eq!(
+    ///     pointer.path,
+    ///     vec![ValuePointerComponent::Key("a/b".to_owned()), ValuePointerComponent::Index(0)]
+    /// );
+    /// assert_eq!(pointer.to_json_path(), r#"$["a/b"][0]"#);
+    /// ```
+    pub fn from_json_pointer(s: &str) -> Result<Self, ParsePointerError> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+        let rest = s
+            .strip_prefix('/')
+            .ok_or_else(|| error("expected `/`", 0))?;
+        let mut path = vec![];
+        let mut offset = 1;
+        for token in rest.split('/') {
+            path.push(parse_reference_token(token, offset)?);
+            offset += token.len() + 1;
+        }
+        Ok(Self { path })
+    }
+
+    /// Parse a JSONPath made of member and index accessors, such as `$.a[0]` or `$["weird key"]`.
+    ///
+    /// Keys in brackets can be quoted with double or single quotes, and may contain the escape
+    /// sequences of JSON strings. Indices cannot have leading zeros. Wildcards, filters, and other JSONPath selectors which can
+    /// match multiple values are not supported.
+    pub fn from_json_path(s: &str) -> Result<Self, ParsePointerError> {
+        let mut parser = PathParser { src: s, pos: 0 };
+        parser.expect('$')?;
+        let mut path = vec![];
+        while let Some(c) = parser.peek() {
+            match c {
+                '.' => {
+                    parser.pos += 1;
+                    let start = parser.pos;
+                    while matches!(parser.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_')
+                    {
+                        parser.pos += 1;
+                    }
+                    if start == parser.pos {
+                        return Err(parser.unexpected());
+                    }
+                    path.push(ValuePointerComponent::Key(s[start..parser.pos].to_owned()));
+                }
+                '[' => {
+                    parser.pos += 1;
+                    let component = match parser.peek() {
+                        Some(quote @ ('"' | '\'')) => {
+                            ValuePointerComponent::Key(parser.parse_quoted(quote)?)
+                        }
+                        Some('0'..='9') => {
+                            let start = parser.pos;
+                            while matches!(parser.peek(), Some('0'..='9')) {
+                                parser.pos += 1;
+                            }
+                            let digits = &s[start..parser.pos];
+                            if digits.len() > 1 && digits.starts_with('0') {
+                                return Err(error(
+                                    "leading zeros are not allowed in an index",
+                                    start,
+                                ));
+                            }
+                            let index = digits
+                                .parse()
+                                .map_err(|_| error("index out of range", start))?;
+                            ValuePointerComponent::Index(index)
+                        }
+                        _ => return Err(parser.unexpected()),
+                    };
+                    parser.expect(']')?;
+                    path.push(component);
+                }
+                _ => return Err(parser.unexpected()),
+            }
+        }
+        Ok(Self { path })
+    }
+}
+
+/// A value tree which can be navigated by a [`ValuePointer`], see [`ValuePointer::resolve`].
+///
+/// It is implemented for [`OwnedValue`] and, with the corresponding features, for
+/// `serde_json::Value`, `toml::Value`, `serde_yaml::Value`, `ciborium::Value`, `rmpv::Value`,
+/// and [`Json5Value`](crate::json5::Json5Value).
+pub trait ValueTree {
+    /// Return the value of the given key, if `self` is a map containing it.
+    fn get_key(&self, key: &str) -> Option<&Self>;
+    /// Return the element at the given index, if `self` is a sequence containing it.
+    fn get_index(&self, index: usize) -> Option<&Self>;
+}
+
+impl ValuePointer {
+    /// Return the value pointed to by `self` within `value`, if there is one.
+    ///
+    /// This is useful to echo back the value that caused a deserialization error. Since
+    /// [JSON Pointers](ValuePointer::from_json_pointer) do not distinguish between keys and
+    /// indices, an index can also select the key of a map with the same digits.
+    ///
+    /// ## Example
+    /// ```
+    /// use jayson::ValuePointer;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({ "users": [{ "age": "ten" }] });
+    /// let pointer: ValuePointer = "/users/0/age".parse().unwrap();
+    /// assert_eq!(pointer.resolve(&value), Some(&json!("ten")));
+    /// ```
+    pub fn resolve<'v, T: ValueTree + ?Sized>(&self, value: &'v T) -> Option<&'v T> {
+        let mut value = value;
+        for component in &self.path {
+            value = match component {
+                ValuePointerComponent::Key(key) => value.get_key(key)?,
+                ValuePointerComponent::Index(index) => match value.get_index(*index) {
+                    Some(value) => value,
+                    None => value.get_key(&index.to_string())?,
+                },
+            };
+        }
+        Some(value)
+    }
+}
+
+impl ValueTree for OwnedValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            OwnedValue::Map(map) => map.get(key),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            OwnedValue::Sequence(seq) => seq.get(index),
+            _ => None,
+        }
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Push `key` to `path` as a double-quoted string, with the escape sequences of JSON
+fn push_quoted(path: &mut String, key: &str) {
+    path.push('"');
+    for c in key.chars() {
+        match c {
+            '"' => path.push_str("\\\""),
+            '\\' => path.push_str("\\\\"),
+            '\n' => path.push_str("\\n"),
+            '\r' => path.push_str("\\r"),
+            '\t' => path.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(path, "\\u{:04x}", c as u32);
+            }
+            c => path.push(c),
+        }
+    }
+    path.push('"');
+}
+
+/// Parse a reference token of a JSON Pointer, which starts at the given offset
+fn parse_reference_token(
+    token: &str,
+    offset: usize,
+) -> Result<ValuePointerComponent, ParsePointerError> {
+    let is_index = match token.as_bytes() {
+        [b'0'] => true,
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    };
+    if is_index {
+        if let Ok(index) = token.parse() {
+            return Ok(ValuePointerComponent::Index(index));
+        }
+    }
+    let mut key = String::with_capacity(token.len());
+    let mut chars = token.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some((_, '0')) => key.push('~'),
+                Some((_, '1')) => key.push('/'),
+                _ => return Err(error("expected `0` or `1` after `~`", offset + i + 1)),
+            }
+        } else {
+            key.push(c);
+        }
+    }
+    Ok(ValuePointerComponent::Key(key))
+}
+
+struct PathParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn unexpected(&self) -> ParsePointerError {
+        match self.peek() {
+            Some(c) => error(format!("unexpected character `{c}`"), self.pos),
+            None => error("unexpected end of path", self.pos),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParsePointerError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(error(format!("expected `{expected}`"), self.pos))
+        }
+    }
+
+    /// Parse a string delimited by `quote`, which is the next character
+    fn parse_quoted(&mut self, quote: char) -> Result<String, ParsePointerError> {
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            let start = self.pos;
+            let c = self
+                .next()
+                .ok_or_else(|| error("unterminated string", self.pos))?;
+            match c {
+                c if c == quote => return Ok(s),
+                '\\' => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('u') => self.parse_unicode_escape(start)?,
+                        Some(c @ ('"' | '\'' | '\\' | '/')) => c,
+                        _ => return Err(error("invalid escape sequence", start)),
+                    };
+                    s.push(escaped);
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// Parse the four hexadecimal digits following `\u`, and the low surrogate that follows
+    /// them if they are a high surrogate. The escape sequence starts at `start`.
+    fn parse_unicode_escape(&mut self, start: usize) -> Result<char, ParsePointerError> {
+        let hex4 = |parser: &mut Self| {
+            let digits = parser
+                .src
+                .get(parser.pos..parser.pos + 4)
+                .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| error("invalid escape sequence", start))?;
+            parser.pos += 4;
+            Ok(digits)
+        };
+        let mut code = hex4(self)?;
+        if (0xD800..0xDC00).contains(&code) && self.src[self.pos..].starts_with("\\u") {
+            self.pos += 2;
+            let low = hex4(self)?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(error("invalid escape sequence", start));
+            }
+            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+        }
+        char::from_u32(code).ok_or_else(|| error("invalid escape sequence", start))
+    }
+}
@@ -0,0 +1,65 @@
+/*!
+Deserialize from URL query strings and `application/x-www-form-urlencoded` data.
+
+All values of a query string are strings. The functions of this module therefore return a
+[`ParseStrings`] value, so that booleans and numbers are parsed from these strings by the
+implementations of [`DeserializeFromValue`](crate::DeserializeFromValue) for `bool`, integers,
+and floats. The same derived type can thus be deserialized from a JSON body and from a query
+string, and return the same errors in both cases.
+
+A key that appears once is read as a string. A key that appears multiple times is read as a
+sequence of strings, in the order in which they appear. A key that appears once can still be
+deserialized into a `Vec<T>` or a set, as a sequence of one element.
+
+## Example
+```
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(DeserializeFromValue)]
+struct Search {
+    q: String,
+    limit: u32,
+    crop: bool,
+}
+
+let value = jayson::query::from_str("q=hello%20world&limit=20&crop=true");
+let search = jayson::deserialize::<Search, _, StandardError>(value).unwrap();
+assert_eq!(search.q, "hello world");
+assert_eq!(search.limit, 20);
+assert!(search.crop);
+```
+*/
+
+use crate::{OwnedMap, OwnedValue, ParseStrings};
+
+/// Parse the given query string, without its leading `?`, into a value readable by Jayson.
+///
+/// Percent-encoded characters and `+` are decoded.
+pub fn from_str(query: &str) -> ParseStrings<OwnedValue> {
+    from_pairs(form_urlencoded::parse(query.as_bytes()))
+}
+
+/// Create a value readable by Jayson from already-decoded key-value pairs.
+pub fn from_pairs<I, K, V>(pairs: I) -> ParseStrings<OwnedValue>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    let mut map = OwnedMap::new();
+    for (key, value) in pairs {
+        let key = key.into();
+        let value = OwnedValue::String(value.into());
+        match map.get_mut(&key) {
+            Some(OwnedValue::Sequence(values)) => values.push(value),
+            Some(existing) => {
+                let first = std::mem::replace(existing, OwnedValue::Null);
+                *existing = OwnedValue::Sequence(vec![first, value]);
+            }
+            None => {
+                map.insert(key, value);
+            }
+        }
+    }
+    ParseStrings(OwnedValue::Map(map))
+}
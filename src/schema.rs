@@ -0,0 +1,459 @@
+use crate::{Base64, Bytes, Hex, OwnedMap, OwnedValue};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::marker::PhantomData;
+
+/// The URI of the JSON Schema dialect used by [`SchemaGenerator`]
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A type whose accepted values can be described by a JSON Schema.
+///
+/// The schema describes the values accepted by the implementation of
+/// [`DeserializeFromValue`](crate::DeserializeFromValue) for the type. The trait can be
+/// derived with the same attributes as `DeserializeFromValue`.
+///
+/// ## Example
+/// ```
+/// use jayson::{schema_for, JsonSchema};
+///
+/// #[derive(JsonSchema)]
+/// #[jayson(rename_all = camelCase, deny_unknown_fields)]
+/// struct Config {
+///     server_name: String,
+///     port: Option<u16>,
+/// }
+///
+/// let schema = serde_json::Value::from(schema_for::<Config>());
+/// assert_eq!(schema["additionalProperties"], false);
+/// assert_eq!(schema["required"], serde_json::json!(["serverName"]));
+/// ```
+pub trait JsonSchema {
+    /// The name of the schema in the `$defs` section of the root schema.
+    ///
+    /// If it is `None`, which is the default, the schema is always inlined. If two different types
+    /// have the same name, the one referenced last is given a name built from its full path
+    /// instead, such as `my_crate.config.Config` (see [`SchemaGenerator::definition_name`]).
+    fn schema_name() -> Option<String> {
+        None
+    }
+
+    /// Identifies the type whose schema is named by [`schema_name`](JsonSchema::schema_name),
+    /// so that different types with the same name can be told apart.
+    ///
+    /// It is the name of the type by default. Wrappers that forward to the schema of another
+    /// type, such as `Box<T>`, return the identifier of that type.
+    fn schema_id() -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Whether a field of this type can be missing from a map.
+    ///
+    /// It is true for the types whose [`DeserializeFromValue::default`](crate::DeserializeFromValue::default)
+    /// method returns `Some`, such as `Option<T>`.
+    fn is_optional() -> bool {
+        false
+    }
+
+    /// Return the JSON Schema describing this type.
+    ///
+    /// The schemas of the inner types should be obtained with [`SchemaGenerator::subschema_for`].
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue;
+}
+
+/// Settings controlling the schemas created by a [`SchemaGenerator`].
+#[derive(Clone, Debug)]
+pub struct SchemaSettings {
+    /// The prefix of the `$ref` references to the named schemas, such as `#/$defs/`.
+    pub definitions_path: String,
+    /// Whether tagged enums should have an OpenAPI `discriminator` object.
+    ///
+    /// If true, the variants of named enums are also added to the definitions, with the name
+    /// `Enum.Variant`, so that the discriminator can map each tag value to its schema.
+    pub discriminator: bool,
+}
+
+impl Default for SchemaSettings {
+    fn default() -> Self {
+        Self {
+            definitions_path: "#/$defs/".to_owned(),
+            discriminator: false,
+        }
+    }
+}
+
+impl SchemaSettings {
+    /// The settings used to create the schemas of the components of an OpenAPI 3.1 document.
+    pub fn openapi3() -> Self {
+        Self {
+            definitions_path: "#/components/schemas/".to_owned(),
+            discriminator: true,
+        }
+    }
+}
+
+/// Builds a JSON Schema along with the definitions of the named types it references.
+#[derive(Clone, Debug, Default)]
+pub struct SchemaGenerator {
+    settings: SchemaSettings,
+    definitions: OwnedMap,
+    /// The name given to the definition of each named type, by [`JsonSchema::schema_id`]
+    names: HashMap<&'static str, String>,
+    /// The names given to the definitions so far
+    taken_names: HashSet<String>,
+}
+
+impl SchemaGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_settings(settings: SchemaSettings) -> Self {
+        Self {
+            settings,
+            ..Self::default()
+        }
+    }
+
+    pub fn settings(&self) -> &SchemaSettings {
+        &self.settings
+    }
+
+    /// Return the schema of `T`, or a reference to it if `T` has a [`schema_name`](JsonSchema::schema_name).
+    ///
+    /// The referenced schema is added to the definitions of the generator.
+    pub fn subschema_for<T: JsonSchema + ?Sized>(&mut self) -> OwnedValue {
+        let name = match self.definition_name::<T>() {
+            Some(name) => name,
+            None => return T::json_schema(self),
+        };
+        if self.definitions.get(&name).is_none() {
+            // insert a placeholder first so that recursive types do not recurse forever
+            self.definitions
+                .insert(name.as_str(), OwnedValue::Boolean(true));
+            let schema = T::json_schema(self);
+            self.definitions.insert(name.as_str(), schema);
+        }
+        let mut reference = OwnedMap::new();
+        reference.insert("$ref", self.definition_ref(&name));
+        OwnedValue::Map(reference)
+    }
+
+    /// Return the name of the definition of `T`, or `None` if `T` has no
+    /// [`schema_name`](JsonSchema::schema_name).
+    ///
+    /// It is the schema name of `T`, unless a different type with the same schema name was seen
+    /// first by this generator. The name is then built from the [`schema_id`](JsonSchema::schema_id)
+    /// of `T`, with `.` instead of `::`, so that the two types do not share the same definition.
+    pub fn definition_name<T: JsonSchema + ?Sized>(&mut self) -> Option<String> {
+        let name = T::schema_name()?;
+        let id = T::schema_id();
+        if let Some(name) = self.names.get(id) {
+            return Some(name.clone());
+        }
+        let name = if self.taken_names.contains(&name) {
+            id.replace("::", ".")
+        } else {
+            name
+        };
+        self.taken_names.insert(name.clone());
+        self.names.insert(id, name.clone());
+        Some(name)
+    }
+
+    /// The definitions of all the named types that were referenced so far.
+    pub fn definitions(&self) -> &OwnedMap {
+        &self.definitions
+    }
+
+    /// Consume the generator and return its definitions.
+    pub fn into_definitions(self) -> OwnedMap {
+        self.definitions
+    }
+
+    /// Add a schema to the definitions, replacing the previous schema with the same name.
+    pub fn insert_definition(&mut self, name: impl Into<String>, schema: OwnedValue) {
+        self.definitions.insert(name, schema);
+    }
+
+    /// Return the reference to the definition with the given name, such as `#/$defs/name`.
+    pub fn definition_ref(&self, name: &str) -> String {
+        format!("{}{name}", self.settings.definitions_path)
+    }
+
+    /// Return the schema of an enum whose variants are distinguished by the value at `tag_key`.
+    ///
+    /// Each variant is given by its name, the values of its tag, and its schema. The first tag
+    /// value is the key of the variant, and the following ones are its aliases, which are all
+    /// listed in the discriminator mapping. The name is only used for the definitions of the
+    /// variants, when the `discriminator` setting is enabled and the name of the enum is known.
+    pub fn tagged_enum_schema(
+        &mut self,
+        enum_name: Option<&str>,
+        tag_key: &str,
+        variants: Vec<(&str, &[&str], OwnedValue)>,
+    ) -> OwnedValue {
+        let mut one_of = Vec::with_capacity(variants.len());
+        let mut mapping = OwnedMap::new();
+        for (variant_name, tag_values, schema) in variants {
+            match enum_name {
+                Some(enum_name) if self.settings.discriminator => {
+                    let name = format!("{enum_name}.{variant_name}");
+                    let reference = self.definition_ref(&name);
+                    self.insert_definition(name, schema);
+                    for tag_value in tag_values {
+                        mapping.insert(*tag_value, reference.as_str());
+                    }
+                    let mut schema = OwnedMap::new();
+                    schema.insert("$ref", reference);
+                    one_of.push(OwnedValue::Map(schema));
+                }
+                _ => one_of.push(schema),
+            }
+        }
+        let mut schema = OwnedMap::new();
+        schema.insert("oneOf", one_of);
+        if self.settings.discriminator {
+            let mut discriminator = OwnedMap::new();
+            discriminator.insert("propertyName", tag_key);
+            if !mapping.is_empty() {
+                discriminator.insert("mapping", mapping);
+            }
+            schema.insert("discriminator", discriminator);
+        }
+        OwnedValue::Map(schema)
+    }
+
+    /// Return the root schema of `T`, which contains the definitions of all the types it references.
+    pub fn into_root_schema_for<T: JsonSchema + ?Sized>(mut self) -> OwnedValue {
+        let name = self.definition_name::<T>();
+        let schema = T::json_schema(&mut self);
+        let mut root = OwnedMap::new();
+        root.insert("$schema", JSON_SCHEMA_DIALECT);
+        if let Some(name) = name {
+            root.insert("title", name);
+        }
+        match schema {
+            OwnedValue::Map(map) => {
+                for (key, value) in map {
+                    root.insert(key, value);
+                }
+            }
+            // `true` accepts any value, so it is equivalent to an empty schema
+            OwnedValue::Boolean(true) => {}
+            schema => {
+                root.insert("allOf", vec![schema]);
+            }
+        }
+        if !self.definitions.is_empty() {
+            root.insert("$defs", self.definitions);
+        }
+        OwnedValue::Map(root)
+    }
+}
+
+/// Return the root JSON Schema of `T`.
+pub fn schema_for<T: JsonSchema + ?Sized>() -> OwnedValue {
+    SchemaGenerator::new().into_root_schema_for::<T>()
+}
+
+fn schema_of_type(ty: &str) -> OwnedMap {
+    let mut schema = OwnedMap::new();
+    schema.insert("type", ty);
+    schema
+}
+
+impl<T: ?Sized> JsonSchema for PhantomData<T> {
+    fn is_optional() -> bool {
+        true
+    }
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Boolean(true)
+    }
+}
+
+impl JsonSchema for () {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("null"))
+    }
+}
+
+impl JsonSchema for bool {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("boolean"))
+    }
+}
+
+macro_rules! schema_impl_integer {
+    ($t:ty) => {
+        impl JsonSchema for $t {
+            fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+                let mut schema = schema_of_type("integer");
+                schema.insert("minimum", <$t>::MIN);
+                schema.insert("maximum", <$t>::MAX);
+                OwnedValue::Map(schema)
+            }
+        }
+    };
+}
+schema_impl_integer!(u8);
+schema_impl_integer!(u16);
+schema_impl_integer!(u32);
+schema_impl_integer!(u64);
+schema_impl_integer!(usize);
+schema_impl_integer!(i8);
+schema_impl_integer!(i16);
+schema_impl_integer!(i32);
+schema_impl_integer!(i64);
+schema_impl_integer!(isize);
+
+impl JsonSchema for f32 {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("number"))
+    }
+}
+
+impl JsonSchema for f64 {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("number"))
+    }
+}
+
+impl JsonSchema for str {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("string"))
+    }
+}
+
+impl JsonSchema for String {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Map(schema_of_type("string"))
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("array");
+        schema.insert("items", gen.subschema_for::<T>());
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn is_optional() -> bool {
+        true
+    }
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = OwnedMap::new();
+        schema.insert(
+            "anyOf",
+            vec![
+                gen.subschema_for::<T>(),
+                OwnedValue::Map(schema_of_type("null")),
+            ],
+        );
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for Box<T> {
+    fn schema_name() -> Option<String> {
+        T::schema_name()
+    }
+    fn schema_id() -> &'static str {
+        T::schema_id()
+    }
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        T::json_schema(gen)
+    }
+}
+
+impl<Key, T: JsonSchema> JsonSchema for HashMap<Key, T> {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("object");
+        schema.insert("additionalProperties", gen.subschema_for::<T>());
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<Key, T: JsonSchema> JsonSchema for BTreeMap<Key, T> {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("object");
+        schema.insert("additionalProperties", gen.subschema_for::<T>());
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for HashSet<T> {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("array");
+        schema.insert("items", gen.subschema_for::<T>());
+        schema.insert("uniqueItems", true);
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for BTreeSet<T> {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("array");
+        schema.insert("items", gen.subschema_for::<T>());
+        schema.insert("uniqueItems", true);
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<A: JsonSchema, B: JsonSchema> JsonSchema for (A, B) {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("array");
+        schema.insert(
+            "prefixItems",
+            vec![gen.subschema_for::<A>(), gen.subschema_for::<B>()],
+        );
+        schema.insert("minItems", 2u64);
+        schema.insert("maxItems", 2u64);
+        OwnedValue::Map(schema)
+    }
+}
+
+impl<A: JsonSchema, B: JsonSchema, C: JsonSchema> JsonSchema for (A, B, C) {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("array");
+        schema.insert(
+            "prefixItems",
+            vec![
+                gen.subschema_for::<A>(),
+                gen.subschema_for::<B>(),
+                gen.subschema_for::<C>(),
+            ],
+        );
+        schema.insert("minItems", 3u64);
+        schema.insert("maxItems", 3u64);
+        OwnedValue::Map(schema)
+    }
+}
+
+impl JsonSchema for OwnedValue {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Boolean(true)
+    }
+}
+
+impl JsonSchema for Bytes {
+    fn json_schema(gen: &mut SchemaGenerator) -> OwnedValue {
+        Vec::<u8>::json_schema(gen)
+    }
+}
+
+impl JsonSchema for Base64 {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("string");
+        schema.insert("contentEncoding", "base64");
+        OwnedValue::Map(schema)
+    }
+}
+
+impl JsonSchema for Hex {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        let mut schema = schema_of_type("string");
+        schema.insert("pattern", "^([0-9a-fA-F]{2})*$");
+        OwnedValue::Map(schema)
+    }
+}
@@ -0,0 +1,374 @@
+//! Interoperability with [`serde`](::serde).
+//!
+//! [`from_deserializer`] buffers the output of any `serde::Deserializer` into a value
+//! that can then be deserialized with [`deserialize`](crate::deserialize). This makes
+//! it possible to use Jayson with every format supported by serde: YAML, TOML,
+//! MessagePack, etc.
+//!
+//! ```
+//! use jayson::{DeserializeFromValue, StandardError};
+//!
+//! #[derive(DeserializeFromValue)]
+//! struct Point {
+//!     x: u8,
+//!     y: u8,
+//! }
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str(r#"{ "x": 1, "y": 2 }"#);
+//! let value = jayson::serde::from_deserializer(&mut deserializer).unwrap();
+//! let point = jayson::deserialize::<Point, _, StandardError>(value).unwrap();
+//! ```
+//!
+//! Conversely, [`Serde<T, E>`], [`deserialize_standard`] and [`deserialize`] make types
+//! implementing [`DeserializeFromValue`] usable inside types deriving `serde::Deserialize`.
+//! This allows migrating a large serde codebase to Jayson incrementally.
+//! [`deserialize_standard`] and `Serde<T>` use [`StandardError`], whereas [`deserialize`] and
+//! `Serde<T, E>` use a custom error type, which must then be given explicitly.
+//!
+//! ```
+//! use jayson::{serde::Serde, DeserializeFromValue, StandardError};
+//!
+//! #[derive(DeserializeFromValue)]
+//! struct Point {
+//!     x: u8,
+//!     y: u8,
+//! }
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Shape {
+//!     name: String,
+//!     #[serde(deserialize_with = "jayson::serde::deserialize_standard")]
+//!     origin: Point,
+//!     #[serde(deserialize_with = "jayson::serde::deserialize::<StandardError, _, _>")]
+//!     center: Point,
+//!     other: Serde<Point>,
+//!     last: Serde<Point, StandardError>,
+//! }
+//!
+//! let shape: Shape = serde_json::from_str(
+//!     r#"{
+//!         "name": "a",
+//!         "origin": { "x": 1, "y": 2 },
+//!         "center": { "x": 2, "y": 3 },
+//!         "other": { "x": 3, "y": 4 },
+//!         "last": { "x": 4, "y": 5 }
+//!     }"#,
+//! )
+//! .unwrap();
+//! assert_eq!(shape.other.into_inner().x, 3);
+//! ```
+
+use crate::{
+    DeserializeError, DeserializeFromValue, ErrorKind, OwnedMap, OwnedValue, StandardError,
+    ValuePointerRef,
+};
+use ::serde::de::{
+    self, Deserialize, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor,
+};
+use ::serde::ser::{Serialize, SerializeMap, Serializer};
+use std::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// Buffer the content of the given serde deserializer into an [`OwnedValue`].
+///
+/// ## Conversion rules
+/// - integers, floats, booleans and strings are converted to the corresponding [`OwnedValue`]
+/// - unit values and `None` are converted to [`OwnedValue::Null`], `Some(x)` to the value of `x`
+/// - byte arrays are converted to [`OwnedValue::Bytes`]
+/// - map keys must be strings, booleans, characters, or numbers. Non-string keys are
+///   converted to their string representation. A map with two keys that are equal once
+///   converted, such as `1` and `"1"`, is rejected with [`ErrorKind::DuplicateKey`].
+///
+/// Enums are not supported by Jayson's value model. An error is returned if the
+/// deserializer encounters one.
+pub fn from_deserializer<'de, D>(deserializer: D) -> Result<OwnedValue, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    OwnedValue::deserialize(deserializer)
+}
+
+/// Deserialize a `T` from the given serde deserializer using its
+/// [`DeserializeFromValue<StandardError>`] implementation.
+///
+/// It is meant to be used with serde's `deserialize_with` attribute:
+/// ```ignore
+/// #[serde(deserialize_with = "jayson::serde::deserialize_standard")]
+/// ```
+/// The [`StandardError`] is converted to a serde error using its `Display` implementation.
+/// Use [`deserialize`] to deserialize with a custom error type instead.
+pub fn deserialize_standard<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeFromValue<StandardError>,
+    D: Deserializer<'de>,
+{
+    deserialize::<StandardError, T, D>(deserializer)
+}
+
+/// Deserialize a `T` from the given serde deserializer using its [`DeserializeFromValue<E>`]
+/// implementation.
+///
+/// It is meant to be used with serde's `deserialize_with` attribute. Since serde cannot
+/// infer the error type, it must be given explicitly:
+/// ```ignore
+/// #[serde(deserialize_with = "jayson::serde::deserialize::<MyError, _, _>")]
+/// ```
+/// A Jayson error is converted to a serde error using its `Display` implementation.
+pub fn deserialize<'de, E, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    E: DeserializeError + Display,
+    T: DeserializeFromValue<E>,
+    D: Deserializer<'de>,
+{
+    let value = OwnedValue::deserialize(deserializer)?;
+    crate::deserialize::<T, _, E>(value).map_err(de::Error::custom)
+}
+
+/// A wrapper implementing `serde::Deserialize` for any type implementing [`DeserializeFromValue<E>`].
+///
+/// The error type defaults to [`StandardError`]. See [`deserialize`] for more information.
+pub struct Serde<T, E = StandardError> {
+    value: T,
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<T, E> Serde<T, E> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, E> Deref for Serde<T, E> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, E> DerefMut for Serde<T, E> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Clone, E> Clone for Serde<T, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, E> PartialEq for Serde<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, E> Eq for Serde<T, E> {}
+
+impl<T: Debug, E> Debug for Serde<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Serde").field(&self.value).finish()
+    }
+}
+
+impl<'de, T, E> Deserialize<'de> for Serde<T, E>
+where
+    E: DeserializeError + Display,
+    T: DeserializeFromValue<E>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize::<E, T, D>(deserializer).map(Self::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnedValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        OwnedValueSeed(ValuePointerRef::Origin).deserialize(deserializer)
+    }
+}
+
+/// Deserialize an [`OwnedValue`] located at the given location, which is only used to
+/// report duplicate keys.
+struct OwnedValueSeed<'a>(ValuePointerRef<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for OwnedValueSeed<'a> {
+    type Value = OwnedValue;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<OwnedValue, D::Error> {
+        deserializer.deserialize_any(OwnedValueVisitor(self.0))
+    }
+}
+
+struct OwnedValueVisitor<'a>(ValuePointerRef<'a>);
+
+impl<'de, 'a> Visitor<'de> for OwnedValueVisitor<'a> {
+    type Value = OwnedValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Boolean(v))
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        if v < 0 {
+            Ok(OwnedValue::NegativeInteger(v))
+        } else {
+            Ok(OwnedValue::Integer(v as u64))
+        }
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Integer(v))
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Float(v))
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(OwnedValue::String(v.to_owned()))
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(OwnedValue::String(v))
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Bytes(v.to_owned()))
+    }
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Bytes(v))
+    }
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Null)
+    }
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        OwnedValueSeed(self.0).deserialize(deserializer)
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(OwnedValue::Null)
+    }
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        OwnedValueSeed(self.0).deserialize(deserializer)
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) =
+            seq.next_element_seed(OwnedValueSeed(self.0.push_index(vec.len())))?
+        {
+            vec.push(value);
+        }
+        Ok(OwnedValue::Sequence(vec))
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut owned_map = OwnedMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(MapKey(key)) = map.next_key()? {
+            let location = self.0.push_key(&key);
+            if owned_map.get(&key).is_some() {
+                let kind = ErrorKind::DuplicateKey { key: &key };
+                let error = match StandardError::invalid_value(None, kind, location) {
+                    Ok(e) | Err(e) => e,
+                };
+                return Err(de::Error::custom(error));
+            }
+            let value = map.next_value_seed(OwnedValueSeed(location))?;
+            owned_map.insert(key, value);
+        }
+        Ok(OwnedValue::Map(owned_map))
+    }
+    fn visit_enum<A: de::EnumAccess<'de>>(self, _data: A) -> Result<Self::Value, A::Error> {
+        Err(de::Error::invalid_type(Unexpected::Enum, &self))
+    }
+}
+
+/// A map key, converted to a string
+struct MapKey(String);
+
+impl<'de> Deserialize<'de> for MapKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MapKeyVisitor).map(MapKey)
+    }
+}
+
+struct MapKeyVisitor;
+
+impl<'de> Visitor<'de> for MapKeyVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string, boolean, character, or number")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_owned())
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
+
+impl Serialize for OwnedValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            OwnedValue::Null => serializer.serialize_unit(),
+            OwnedValue::Boolean(b) => serializer.serialize_bool(*b),
+            OwnedValue::Integer(x) => serializer.serialize_u64(*x),
+            OwnedValue::NegativeInteger(x) => serializer.serialize_i64(*x),
+            OwnedValue::Float(x) => serializer.serialize_f64(*x),
+            OwnedValue::String(x) => serializer.serialize_str(x),
+            OwnedValue::Bytes(x) => serializer.serialize_bytes(x),
+            OwnedValue::Sequence(x) => x.serialize(serializer),
+            OwnedValue::Map(x) => x.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for OwnedMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
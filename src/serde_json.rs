@@ -1,6 +1,6 @@
 use crate::{
-    DeserializeError, DeserializeFromValue, IntoValue, Map, Sequence, Value, ValueKind,
-    ValuePointerRef,
+    DeserializeError, DeserializeFromValue, ErrorKind, IntoValue, JsonSchema, Map, OwnedValue,
+    SchemaGenerator, Sequence, Value, ValueKind, ValuePointerRef, ValueTree,
 };
 use serde_json::{Map as JMap, Number, Value as JValue};
 
@@ -80,20 +80,25 @@ impl<E: DeserializeError> DeserializeFromValue<E> for JValue {
             Value::Float(f) => match Number::from_f64(f) {
                 Some(n) => JValue::Number(n),
                 None => {
-                    return Err(E::unexpected(
+                    return Err(E::invalid_value(
                         error,
-                        &format!("The float {f} is not representable in JSON"),
+                        ErrorKind::UnrepresentableFloat {
+                            value: f,
+                            format: "JSON",
+                        },
                         location,
                     )?);
                 }
             },
             Value::String(s) => JValue::String(s),
+            Value::Bytes(bytes) => JValue::Array(bytes.into_iter().map(JValue::from).collect()),
             Value::Sequence(seq) => {
                 let mut jseq = Vec::with_capacity(seq.len());
                 for (index, value) in seq.into_iter().enumerate() {
+                    let span = value.span();
                     let result = Self::deserialize_from_value(
                         value.into_value(),
-                        location.push_index(index),
+                        location.push_index(index).with_span(span.as_ref()),
                     );
                     match result {
                         Ok(value) => {
@@ -113,8 +118,11 @@ impl<E: DeserializeError> DeserializeFromValue<E> for JValue {
             Value::Map(map) => {
                 let mut jmap = JMap::with_capacity(map.len());
                 for (key, value) in map.into_iter() {
-                    let result =
-                        Self::deserialize_from_value(value.into_value(), location.push_key(&key));
+                    let span = value.span();
+                    let result = Self::deserialize_from_value(
+                        value.into_value(),
+                        location.push_key(&key).with_span(span.as_ref()),
+                    );
                     match result {
                         Ok(value) => {
                             jmap.insert(key, value);
@@ -133,3 +141,56 @@ impl<E: DeserializeError> DeserializeFromValue<E> for JValue {
         })
     }
 }
+
+impl JsonSchema for JValue {
+    fn json_schema(_gen: &mut SchemaGenerator) -> OwnedValue {
+        OwnedValue::Boolean(true)
+    }
+}
+
+impl From<JValue> for OwnedValue {
+    fn from(value: JValue) -> Self {
+        OwnedValue::from_validated_value(value.into_value())
+    }
+}
+
+/// Floats that are not representable in JSON (NaN and infinities) are converted to `null`,
+/// and bytes are converted to arrays of numbers.
+impl From<OwnedValue> for JValue {
+    fn from(value: OwnedValue) -> Self {
+        match value {
+            OwnedValue::Null => JValue::Null,
+            OwnedValue::Boolean(b) => JValue::Bool(b),
+            OwnedValue::Integer(x) => JValue::Number(Number::from(x)),
+            OwnedValue::NegativeInteger(x) => JValue::Number(Number::from(x)),
+            OwnedValue::Float(f) => Number::from_f64(f).map_or(JValue::Null, JValue::Number),
+            OwnedValue::String(s) => JValue::String(s),
+            OwnedValue::Bytes(bytes) => {
+                JValue::Array(bytes.into_iter().map(JValue::from).collect())
+            }
+            OwnedValue::Sequence(seq) => {
+                JValue::Array(IntoIterator::into_iter(seq).map(JValue::from).collect())
+            }
+            OwnedValue::Map(map) => JValue::Object(
+                IntoIterator::into_iter(map)
+                    .map(|(key, value)| (key, JValue::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl ValueTree for JValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            JValue::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            JValue::Array(seq) => seq.get(index),
+            _ => None,
+        }
+    }
+}
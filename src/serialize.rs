@@ -0,0 +1,179 @@
+use crate::{Base64, Bytes, Hex, OwnedMap, OwnedValue};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    marker::PhantomData,
+};
+
+/// A type that can be converted to an [`OwnedValue`].
+///
+/// It is the counterpart of [`DeserializeFromValue`](crate::DeserializeFromValue). It can be
+/// derived with the same attributes, so that a value serialized by `SerializeIntoValue` can be
+/// deserialized back by `DeserializeFromValue`.
+///
+/// The resulting [`OwnedValue`] can then be converted to the value of a specific format, such
+/// as `serde_json::Value`.
+pub trait SerializeIntoValue {
+    fn serialize_into_value(&self) -> OwnedValue;
+}
+
+impl<T: SerializeIntoValue + ?Sized> SerializeIntoValue for &T {
+    fn serialize_into_value(&self) -> OwnedValue {
+        (**self).serialize_into_value()
+    }
+}
+
+impl<T> SerializeIntoValue for PhantomData<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Null
+    }
+}
+
+impl SerializeIntoValue for () {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Null
+    }
+}
+
+impl SerializeIntoValue for bool {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Boolean(*self)
+    }
+}
+
+macro_rules! serialize_impl_number {
+    ($t:ty) => {
+        impl SerializeIntoValue for $t {
+            fn serialize_into_value(&self) -> OwnedValue {
+                OwnedValue::from(*self)
+            }
+        }
+    };
+}
+serialize_impl_number!(u8);
+serialize_impl_number!(u16);
+serialize_impl_number!(u32);
+serialize_impl_number!(u64);
+serialize_impl_number!(usize);
+serialize_impl_number!(i8);
+serialize_impl_number!(i16);
+serialize_impl_number!(i32);
+serialize_impl_number!(i64);
+serialize_impl_number!(isize);
+serialize_impl_number!(f32);
+serialize_impl_number!(f64);
+
+impl SerializeIntoValue for str {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::String(self.to_owned())
+    }
+}
+
+impl SerializeIntoValue for String {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::String(self.clone())
+    }
+}
+
+impl<T: SerializeIntoValue> SerializeIntoValue for [T] {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Sequence(self.iter().map(T::serialize_into_value).collect())
+    }
+}
+
+impl<T: SerializeIntoValue> SerializeIntoValue for Vec<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        self.as_slice().serialize_into_value()
+    }
+}
+
+impl<T: SerializeIntoValue> SerializeIntoValue for Option<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        match self {
+            Some(x) => x.serialize_into_value(),
+            None => OwnedValue::Null,
+        }
+    }
+}
+
+impl<T: SerializeIntoValue + ?Sized> SerializeIntoValue for Box<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        (**self).serialize_into_value()
+    }
+}
+
+impl<Key: ToString, T: SerializeIntoValue> SerializeIntoValue for HashMap<Key, T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_string(), v.serialize_into_value()))
+                .collect::<OwnedMap>(),
+        )
+    }
+}
+
+impl<Key: ToString, T: SerializeIntoValue> SerializeIntoValue for BTreeMap<Key, T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_string(), v.serialize_into_value()))
+                .collect::<OwnedMap>(),
+        )
+    }
+}
+
+impl<T: SerializeIntoValue> SerializeIntoValue for HashSet<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Sequence(self.iter().map(T::serialize_into_value).collect())
+    }
+}
+
+impl<T: SerializeIntoValue> SerializeIntoValue for BTreeSet<T> {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Sequence(self.iter().map(T::serialize_into_value).collect())
+    }
+}
+
+impl<A: SerializeIntoValue, B: SerializeIntoValue> SerializeIntoValue for (A, B) {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Sequence(vec![
+            self.0.serialize_into_value(),
+            self.1.serialize_into_value(),
+        ])
+    }
+}
+
+impl<A: SerializeIntoValue, B: SerializeIntoValue, C: SerializeIntoValue> SerializeIntoValue
+    for (A, B, C)
+{
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Sequence(vec![
+            self.0.serialize_into_value(),
+            self.1.serialize_into_value(),
+            self.2.serialize_into_value(),
+        ])
+    }
+}
+
+impl SerializeIntoValue for OwnedValue {
+    fn serialize_into_value(&self) -> OwnedValue {
+        self.clone()
+    }
+}
+
+impl SerializeIntoValue for Bytes {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::Bytes(self.0.clone())
+    }
+}
+
+impl SerializeIntoValue for Base64 {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::String(crate::bytes::encode_base64(&self.0))
+    }
+}
+
+impl SerializeIntoValue for Hex {
+    fn serialize_into_value(&self) -> OwnedValue {
+        OwnedValue::String(crate::bytes::encode_hex(&self.0))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{IntoValue, Map, Sequence, Value, ValueKind};
+use std::fmt::{self, Display};
+
+/// A lightweight rendering of a [`Value`], given to
+/// [`DeserializeError::incorrect_value`](crate::DeserializeError::incorrect_value).
+///
+/// Scalars are kept as they are, except for strings, which are truncated to
+/// [`MAX_PREVIEW_CHARS`](ValueSnapshot::MAX_PREVIEW_CHARS) characters. Only the length of
+/// bytes, sequences, and maps is kept.
+///
+/// The [`Display`] implementation renders the snapshot along with its kind, such as
+/// `string "abc"` or `a sequence of 2 elements`, which can be used in error messages.
+///
+/// ## Example
+/// ```
+/// use jayson::{IntoValue, OwnedValue, ValueSnapshot};
+///
+/// let snapshot = ValueSnapshot::of(&OwnedValue::from("abc").into_value());
+/// assert_eq!(snapshot.to_string(), r#"string "abc""#);
+///
+/// let snapshot = ValueSnapshot::of(&OwnedValue::from(vec![1, 2]).into_value());
+/// assert_eq!(snapshot, ValueSnapshot::Sequence { len: 2 });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueSnapshot {
+    Null,
+    Boolean(bool),
+    Integer(u64),
+    NegativeInteger(i64),
+    Float(f64),
+    String {
+        /// The first characters of the string
+        preview: String,
+        /// Whether the string is longer than its preview
+        truncated: bool,
+    },
+    Bytes {
+        len: usize,
+    },
+    Sequence {
+        len: usize,
+    },
+    Map {
+        len: usize,
+    },
+}
+
+impl ValueSnapshot {
+    /// The maximum number of characters of the preview of a string.
+    pub const MAX_PREVIEW_CHARS: usize = 64;
+
+    /// Return the snapshot of the given value.
+    pub fn of<V: IntoValue>(value: &Value<V>) -> Self {
+        match value {
+            Value::Null => ValueSnapshot::Null,
+            Value::Boolean(b) => ValueSnapshot::Boolean(*b),
+            Value::Integer(x) => ValueSnapshot::Integer(*x),
+            Value::NegativeInteger(x) => ValueSnapshot::NegativeInteger(*x),
+            Value::Float(x) => ValueSnapshot::Float(*x),
+            Value::String(s) => {
+                let preview = s.chars().take(Self::MAX_PREVIEW_CHARS).collect::<String>();
+                let truncated = preview.len() < s.len();
+                ValueSnapshot::String { preview, truncated }
+            }
+            Value::Bytes(bytes) => ValueSnapshot::Bytes { len: bytes.len() },
+            Value::Sequence(seq) => ValueSnapshot::Sequence { len: seq.len() },
+            Value::Map(map) => ValueSnapshot::Map { len: map.len() },
+        }
+    }
+
+    /// Return the kind of the value.
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            ValueSnapshot::Null => ValueKind::Null,
+            ValueSnapshot::Boolean(_) => ValueKind::Boolean,
+            ValueSnapshot::Integer(_) => ValueKind::Integer,
+            ValueSnapshot::NegativeInteger(_) => ValueKind::NegativeInteger,
+            ValueSnapshot::Float(_) => ValueKind::Float,
+            ValueSnapshot::String { .. } => ValueKind::String,
+            ValueSnapshot::Bytes { .. } => ValueKind::Bytes,
+            ValueSnapshot::Sequence { .. } => ValueKind::Sequence,
+            ValueSnapshot::Map { .. } => ValueKind::Map,
+        }
+    }
+}
+
+impl Display for ValueSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |len: &usize| if *len == 1 { "" } else { "s" };
+        match self {
+            ValueSnapshot::Null => write!(f, "null"),
+            ValueSnapshot::Boolean(b) => write!(f, "boolean {b}"),
+            ValueSnapshot::Integer(x) => write!(f, "integer {x}"),
+            ValueSnapshot::NegativeInteger(x) => write!(f, "integer {x}"),
+            ValueSnapshot::Float(x) => write!(f, "float {x}"),
+            ValueSnapshot::String { preview, truncated } => {
+                write!(f, "string {preview:?}")?;
+                if *truncated {
+                    write!(f, "…")?;
+                }
+                Ok(())
+            }
+            ValueSnapshot::Bytes { len } => write!(f, "{len} byte{}", plural(len)),
+            ValueSnapshot::Sequence { len } => {
+                write!(f, "a sequence of {len} element{}", plural(len))
+            }
+            ValueSnapshot::Map { len } => {
+                let entries = if *len == 1 { "entry" } else { "entries" };
+                write!(f, "a map of {len} {entries}")
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    unknown_message, DeserializeError, ErrorKind, MergeWithError, ValueKind, ValuePointer,
+    ValuePointerRef, ValueSnapshot,
+};
+use std::fmt::{self, Display};
+
+/// A ready-made [`DeserializeError`] holding an English message and the location of the error.
+///
+/// Deserialization stops at the first error. It is useful when no custom error type is needed,
+/// and in places where the error type cannot be inferred, such as serde's `deserialize_with`
+/// attribute (see [`serde::deserialize_standard`](crate::serde)).
+///
+/// ## Example
+/// ```
+/// use jayson::{DeserializeFromValue, StandardError};
+///
+/// #[derive(Debug, DeserializeFromValue)]
+/// struct Point {
+///     x: u8,
+///     y: u8,
+/// }
+///
+/// let value: serde_json::Value = serde_json::json!({ "x": 1, "y": true });
+/// let error = jayson::deserialize::<Point, _, StandardError>(value).unwrap_err();
+/// assert_eq!(error.to_string(), "expected Integer, found boolean true at `/y`");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StandardError {
+    /// The description of the error
+    pub message: String,
+    /// The location of the offending value
+    pub location: ValuePointer,
+}
+
+impl StandardError {
+    fn new(message: impl Into<String>, location: ValuePointerRef) -> Self {
+        Self {
+            message: message.into(),
+            location: location.to_owned(),
+        }
+    }
+}
+
+impl Display for StandardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if !self.location.path.is_empty() {
+            write!(f, " at `{}`", self.location)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StandardError {}
+
+impl MergeWithError<StandardError> for StandardError {
+    fn merge(
+        _self_: Option<Self>,
+        other: StandardError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+
+impl DeserializeError for StandardError {
+    fn location(&self) -> Option<ValuePointer> {
+        Some(self.location.clone())
+    }
+
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        actual: ValueKind,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(
+            format!("expected {}, found {actual}", expected_kinds(accepted)),
+            location,
+        ))
+    }
+
+    fn incorrect_value(
+        _self_: Option<Self>,
+        actual: ValueSnapshot,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(
+            format!("expected {}, found {actual}", expected_kinds(accepted)),
+            location,
+        ))
+    }
+
+    fn missing_field(
+        _self_: Option<Self>,
+        field: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(format!("missing field `{field}`"), location))
+    }
+
+    fn unknown_key(
+        _self_: Option<Self>,
+        key: &str,
+        accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(unknown_message("key", key, accepted), location))
+    }
+
+    fn invalid_value(
+        _self_: Option<Self>,
+        kind: ErrorKind,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(kind.to_string(), location))
+    }
+
+    fn unexpected(
+        _self_: Option<Self>,
+        msg: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(msg, location))
+    }
+}
+
+/// Return the accepted kinds as in `String or Map`.
+fn expected_kinds(accepted: &[ValueKind]) -> String {
+    accepted
+        .iter()
+        .map(|kind| kind.to_string())
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
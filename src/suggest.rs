@@ -0,0 +1,95 @@
+/// Return the Levenshtein distance between two strings, which is the minimum number of
+/// single-character insertions, deletions, and substitutions needed to change one into the other.
+///
+/// ## Example
+/// ```
+/// assert_eq!(jayson::levenshtein_distance("kitten", "sitting"), 3);
+/// ```
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    // the distances between the first `i` characters of `a` and the first `j` characters of `b`,
+    // for the previous and current values of `i`
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Return the candidate that is the most similar to `found`, if it is similar enough to
+/// be a likely typo.
+///
+/// A candidate differing from `found` only by its case is always preferred. Otherwise, the
+/// candidate with the smallest [Levenshtein distance](levenshtein_distance) is returned
+/// if that distance is at most a third of the length of `found`, and at least 1.
+///
+/// ## Example
+/// ```
+/// use jayson::did_you_mean;
+///
+/// let accepted = ["color", "size", "weight"];
+/// assert_eq!(did_you_mean("colr", &accepted), Some("color"));
+/// assert_eq!(did_you_mean("Size", &accepted), Some("size"));
+/// assert_eq!(did_you_mean("height", &accepted), Some("weight"));
+/// assert_eq!(did_you_mean("name", &accepted), None);
+/// ```
+pub fn did_you_mean<'a>(found: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    if let Some(candidate) = candidates
+        .iter()
+        .find(|candidate| candidate.to_lowercase() == found.to_lowercase())
+    {
+        return Some(candidate);
+    }
+    let max_distance = (found.chars().count() / 3).max(1);
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein_distance(found, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Return an English message describing an unknown key or value, which lists the
+/// accepted ones and suggests the most similar one according to [`did_you_mean`].
+///
+/// `what` describes the unknown string, such as `"key"` or `"tag value"`.
+///
+/// ## Example
+/// ```
+/// use jayson::unknown_message;
+///
+/// assert_eq!(
+///     unknown_message("key", "colr", &["color", "size"]),
+///     "Unknown key `colr`: expected one of `color`, `size`. Did you mean `color`?"
+/// );
+/// assert_eq!(
+///     unknown_message("tag value", "Square", &["Circle"]),
+///     "Unknown tag value `Square`: expected `Circle`."
+/// );
+/// ```
+pub fn unknown_message(what: &str, found: &str, accepted: &[&str]) -> String {
+    let mut message = format!("Unknown {what} `{found}`");
+    match accepted {
+        [] => {}
+        [accepted] => message.push_str(&format!(": expected `{accepted}`")),
+        accepted => {
+            let list = accepted
+                .iter()
+                .map(|a| format!("`{a}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            message.push_str(&format!(": expected one of {list}"));
+        }
+    }
+    message.push('.');
+    if let Some(suggestion) = did_you_mean(found, accepted) {
+        message.push_str(&format!(" Did you mean `{suggestion}`?"));
+    }
+    message
+}
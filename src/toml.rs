@@ -0,0 +1,72 @@
+use crate::{IntoValue, Map, Value, ValueKind, ValueTree};
+use toml::{map::Map as TMap, Value as TValue};
+
+impl Map for TMap<String, TValue> {
+    type Value = TValue;
+    type Iter = <Self as IntoIterator>::IntoIter;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn remove(&mut self, key: &str) -> Option<Self::Value> {
+        self.remove(key)
+    }
+    fn into_iter(self) -> Self::Iter {
+        <Self as IntoIterator>::into_iter(self)
+    }
+}
+
+impl IntoValue for TValue {
+    type Sequence = Vec<TValue>;
+    type Map = TMap<String, TValue>;
+
+    fn into_value(self) -> Value<Self> {
+        match self {
+            TValue::Boolean(b) => Value::Boolean(b),
+            TValue::Integer(n) => {
+                if n < 0 {
+                    Value::NegativeInteger(n)
+                } else {
+                    Value::Integer(n as u64)
+                }
+            }
+            TValue::Float(f) => Value::Float(f),
+            TValue::String(x) => Value::String(x),
+            TValue::Datetime(x) => Value::String(x.to_string()),
+            TValue::Array(x) => Value::Sequence(x),
+            TValue::Table(x) => Value::Map(x),
+        }
+    }
+
+    fn kind(&self) -> ValueKind {
+        match self {
+            TValue::Boolean(_) => ValueKind::Boolean,
+            TValue::Integer(n) => {
+                if *n < 0 {
+                    ValueKind::NegativeInteger
+                } else {
+                    ValueKind::Integer
+                }
+            }
+            TValue::Float(_) => ValueKind::Float,
+            TValue::String(_) | TValue::Datetime(_) => ValueKind::String,
+            TValue::Array(_) => ValueKind::Sequence,
+            TValue::Table(_) => ValueKind::Map,
+        }
+    }
+}
+
+impl ValueTree for TValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            TValue::Table(table) => table.get(key),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            TValue::Array(array) => array.get(index),
+            _ => None,
+        }
+    }
+}
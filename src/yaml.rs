@@ -0,0 +1,197 @@
+use crate::flow::{find_key, key_to_string, string_keys, Flow, FlowValue};
+use crate::{DeserializeError, IntoValue, Value, ValueKind, ValuePointerRef, ValueTree};
+use serde_yaml::{value::Tag, Mapping, Value as YValue};
+use std::collections::HashSet;
+
+impl IntoValue for YValue {
+    type Sequence = Vec<YValue>;
+    type Map = Vec<(String, YValue)>;
+
+    fn into_value(self) -> Value<Self> {
+        match self {
+            YValue::Null => Value::Null,
+            YValue::Bool(b) => Value::Boolean(b),
+            YValue::Number(n) => match (n.as_u64(), n.as_i64(), n.as_f64()) {
+                (Some(n), _, _) => Value::Integer(n),
+                (None, Some(n), _) => Value::NegativeInteger(n),
+                (None, None, n) => Value::Float(n.unwrap_or(f64::NAN)),
+            },
+            YValue::String(x) => Value::String(x),
+            YValue::Sequence(x) => Value::Sequence(x),
+            YValue::Mapping(x) => Value::Map(
+                resolve_merge_keys(x)
+                    .into_iter()
+                    .map(|(k, v)| (key_to_string(&k), v))
+                    .collect(),
+            ),
+            YValue::Tagged(tagged) => {
+                let tag = tag_name(&tagged.tag);
+                Value::Map(vec![(tag, tagged.value)])
+            }
+        }
+    }
+
+    fn kind(&self) -> ValueKind {
+        match self {
+            YValue::Null => ValueKind::Null,
+            YValue::Bool(_) => ValueKind::Boolean,
+            YValue::Number(n) if n.is_u64() => ValueKind::Integer,
+            YValue::Number(n) if n.is_i64() => ValueKind::NegativeInteger,
+            YValue::Number(_) => ValueKind::Float,
+            YValue::String(_) => ValueKind::String,
+            YValue::Sequence(_) => ValueKind::Sequence,
+            YValue::Mapping(_) | YValue::Tagged(_) => ValueKind::Map,
+        }
+    }
+
+    /// Reject the mappings with two keys that are equal once converted to strings,
+    /// such as `1` and `"1"`.
+    fn validate<E: DeserializeError>(&self, location: ValuePointerRef) -> Result<(), E> {
+        match self {
+            YValue::Sequence(seq) => {
+                for (i, value) in seq.iter().enumerate() {
+                    value.validate(location.push_index(i))?;
+                }
+                Ok(())
+            }
+            YValue::Mapping(mapping) => {
+                let mut entries = Vec::with_capacity(mapping.len());
+                merged_entries(mapping, &mut HashSet::new(), &mut entries);
+                for (key, value) in string_keys(entries, location)? {
+                    value.validate(location.push_key(&key))?;
+                }
+                Ok(())
+            }
+            YValue::Tagged(tagged) => {
+                let tag = tag_name(&tagged.tag);
+                tagged.value.validate(location.push_key(&tag))
+            }
+            YValue::Null | YValue::Bool(_) | YValue::Number(_) | YValue::String(_) => Ok(()),
+        }
+    }
+}
+
+/// Keys are compared once converted to strings, merge keys are followed, and a tagged value
+/// is a map whose only key is the name of the tag, as when deserializing.
+impl ValueTree for YValue {
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            YValue::Mapping(mapping) => {
+                let merged = match mapping.get("<<") {
+                    Some(merged @ (YValue::Mapping(_) | YValue::Sequence(_))) => Some(merged),
+                    _ => None,
+                };
+                let entries = mapping
+                    .iter()
+                    .filter(|(k, _)| merged.is_none() || k.as_str() != Some("<<"));
+                if let Some(value) = find_key(entries, key) {
+                    return Some(value);
+                }
+                // the earlier merged mappings take precedence over the later ones
+                match merged {
+                    Some(merged @ YValue::Mapping(_)) => merged.get_key(key),
+                    Some(YValue::Sequence(seq)) => seq
+                        .iter()
+                        .filter(|v| v.is_mapping())
+                        .find_map(|v| v.get_key(key)),
+                    _ => None,
+                }
+            }
+            YValue::Tagged(tagged) if tag_name(&tagged.tag) == key => Some(&tagged.value),
+            _ => None,
+        }
+    }
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        match self {
+            YValue::Sequence(seq) => seq.get(index),
+            _ => None,
+        }
+    }
+}
+
+/// Return the name of a tag, without its leading `!`.
+fn tag_name(tag: &Tag) -> String {
+    let tag = tag.to_string();
+    tag.strip_prefix('!').unwrap_or(&tag).to_owned()
+}
+
+/// Merge the mappings referenced by the `<<` key, if any, into the given mapping.
+///
+/// Keys defined in the mapping itself take precedence over merged keys. When `<<` refers
+/// to a sequence of mappings, the earlier mappings take precedence over the later ones.
+/// The other keys keep their order.
+fn resolve_merge_keys(mut mapping: Mapping) -> Mapping {
+    let merged = match mapping.get("<<") {
+        Some(YValue::Mapping(_) | YValue::Sequence(_)) => mapping.shift_remove("<<"),
+        // not a valid merge key, keep it as a regular key
+        _ => None,
+    };
+    let merged = match merged {
+        Some(YValue::Mapping(m)) => vec![m],
+        Some(YValue::Sequence(seq)) => seq
+            .into_iter()
+            .filter_map(|v| match v {
+                YValue::Mapping(m) => Some(m),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    for merged_mapping in merged {
+        for (key, value) in resolve_merge_keys(merged_mapping) {
+            if !mapping.contains_key(&key) {
+                mapping.insert(key, value);
+            }
+        }
+    }
+    mapping
+}
+
+/// Collect the entries of the given mapping once its merge keys are resolved, in the same
+/// order as [`resolve_merge_keys`], but without cloning the merged mappings. The keys already
+/// in `seen` are skipped.
+fn merged_entries<'a>(
+    mapping: &'a Mapping,
+    seen: &mut HashSet<&'a YValue>,
+    entries: &mut Vec<(&'a YValue, &'a YValue)>,
+) {
+    let merged = match mapping.get("<<") {
+        Some(merged @ (YValue::Mapping(_) | YValue::Sequence(_))) => Some(merged),
+        _ => None,
+    };
+    for (key, value) in mapping {
+        if merged.is_some() && key.as_str() == Some("<<") {
+            continue;
+        }
+        if seen.insert(key) {
+            entries.push((key, value));
+        }
+    }
+    match merged {
+        Some(YValue::Mapping(m)) => merged_entries(m, seen, entries),
+        Some(YValue::Sequence(seq)) => {
+            for v in seq {
+                if let YValue::Mapping(m) = v {
+                    merged_entries(m, seen, entries);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+impl FlowValue for YValue {
+    fn flow(&self) -> Flow<'_, Self> {
+        match self {
+            YValue::Null => Flow::Text("null".into()),
+            YValue::Bool(b) => Flow::Text(b.to_string().into()),
+            YValue::Number(n) => Flow::Text(n.to_string().into()),
+            YValue::String(x) => Flow::Text(x.into()),
+            YValue::Sequence(seq) => Flow::Sequence(seq),
+            YValue::Mapping(map) => Flow::Map(map.iter().collect()),
+            YValue::Tagged(tagged) => {
+                Flow::Prefixed(format!("{} ", tagged.tag).into(), &tagged.value)
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use jayson::args::{self, ArgsError};
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Options {
+    verbose: bool,
+    color: Option<bool>,
+    offset: Option<i32>,
+    include: Option<Vec<String>>,
+    db: Db,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Db {
+    pool_size: u32,
+    url: Option<String>,
+}
+
+#[test]
+fn deserialize_args() {
+    let value = args::parse([
+        "--verbose",
+        "--no-color",
+        "--offset",
+        "-3",
+        "--include=a",
+        "--include",
+        "b",
+        "--db.pool-size=10",
+        "--db.url",
+        "postgres://localhost",
+    ])
+    .unwrap();
+    let options = jayson::deserialize::<Options, _, StandardError>(value).unwrap();
+    assert_eq!(
+        options,
+        Options {
+            verbose: true,
+            color: Some(false),
+            offset: Some(-3),
+            include: Some(vec!["a".to_owned(), "b".to_owned()]),
+            db: Db {
+                pool_size: 10,
+                url: Some("postgres://localhost".to_owned())
+            }
+        }
+    );
+}
+
+#[test]
+fn flag_followed_by_option() {
+    let value = args::parse(["--verbose", "--db.pool-size", "1"]).unwrap();
+    let options = jayson::deserialize::<Options, _, StandardError>(value).unwrap();
+    assert!(options.verbose);
+}
+
+#[test]
+fn malformed_args() {
+    assert_eq!(
+        args::parse(["value"]),
+        Err(ArgsError::UnexpectedArgument("value".to_owned()))
+    );
+    assert_eq!(
+        args::parse(["--a", "b", "c"]),
+        Err(ArgsError::UnexpectedArgument("c".to_owned()))
+    );
+    assert_eq!(
+        args::parse(["--"]),
+        Err(ArgsError::InvalidKey("--".to_owned()))
+    );
+    assert_eq!(
+        args::parse(["--a..b=1"]),
+        Err(ArgsError::InvalidKey("--a..b=1".to_owned()))
+    );
+    assert_eq!(
+        args::parse(["--a=1", "--a.b=2"]),
+        Err(ArgsError::ConflictingKey("a.b".to_owned()))
+    );
+    assert_eq!(
+        args::parse(["--a.b=2", "--a=1"]),
+        Err(ArgsError::ConflictingKey("a".to_owned()))
+    );
+    assert_eq!(
+        ArgsError::ConflictingKey("a".to_owned()).to_string(),
+        "the option `--a` is given both as a value and as a map"
+    );
+}
+
+#[test]
+fn errors_point_to_options() {
+    let value = args::parse(["--verbose", "--db.pool-size=-1"]).unwrap();
+    let error = jayson::deserialize::<Options, _, StandardError>(value).unwrap_err();
+    assert_eq!(args::option_name(&error.location), "--db.pool-size");
+
+    let value = args::parse(["--verbose"]).unwrap();
+    let error = jayson::deserialize::<Options, _, StandardError>(value).unwrap_err();
+    assert_eq!(error.message, "missing field `db`");
+
+    let value = args::parse(["--verbose", "--db.pool-size=1", "--db.uri=x"]).unwrap();
+    let error = jayson::deserialize::<Options, _, StandardError>(value).unwrap_err();
+    assert_eq!(
+        error.message,
+        "Unknown key `uri`: expected one of `pool_size`, `url`. Did you mean `url`?"
+    );
+    assert_eq!(args::option_name(&error.location), "--db");
+}
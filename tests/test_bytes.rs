@@ -0,0 +1,119 @@
+use jayson::{Base64, Bytes, DeserializeFromValue, Hex, OwnedValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+struct Upload {
+    raw: Vec<u8>,
+    bytes: Bytes,
+    base64: Base64,
+    hex: Hex,
+}
+
+#[track_caller]
+fn from_json<T: DeserializeFromValue<StandardError>>(s: &str) -> Result<T, StandardError> {
+    let value: serde_json::Value = serde_json::from_str(s).unwrap();
+    jayson::deserialize(value)
+}
+
+#[test]
+fn bytes_from_json() {
+    let upload = from_json::<Upload>(
+        r#"{ "raw": [1, 2], "bytes": [3, 255], "base64": "aGVsbG8=", "hex": "00fF10" }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        upload,
+        Upload {
+            raw: vec![1, 2],
+            bytes: Bytes(vec![3, 255]),
+            base64: Base64(b"hello".to_vec()),
+            hex: Hex(vec![0, 255, 16]),
+        }
+    );
+}
+
+#[test]
+fn bytes_from_binary_value() {
+    let map: jayson::OwnedMap = [
+        ("raw", OwnedValue::Bytes(vec![1, 2])),
+        ("bytes", OwnedValue::Bytes(vec![3])),
+        ("base64", OwnedValue::Bytes(vec![4])),
+        ("hex", OwnedValue::Bytes(vec![5])),
+    ]
+    .into_iter()
+    .collect();
+    let upload = jayson::deserialize::<Upload, _, StandardError>(OwnedValue::Map(map)).unwrap();
+    assert_eq!(
+        upload,
+        Upload {
+            raw: vec![1, 2],
+            bytes: Bytes(vec![3]),
+            base64: Base64(vec![4]),
+            hex: Hex(vec![5]),
+        }
+    );
+
+    let error = jayson::deserialize::<Vec<i8>, _, StandardError>(OwnedValue::Bytes(vec![1, 200]))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize 200 into a i8 at `/1`"
+    );
+}
+
+#[test]
+fn base64_alphabets_and_padding() {
+    for s in ["+/8=", "-_8", "+/8"] {
+        let value = OwnedValue::from(s);
+        let decoded = jayson::deserialize::<Base64, _, StandardError>(value).unwrap();
+        assert_eq!(decoded, Base64(vec![0xfb, 0xff]));
+    }
+    let decoded = jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("")).unwrap();
+    assert_eq!(decoded, Base64(vec![]));
+}
+
+#[test]
+fn invalid_encodings() {
+    let error =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("aGV!")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid base64 string: invalid character `!` at position 3"
+    );
+    let error =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("aGVsb")).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid base64 string: invalid length");
+    let error =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("QUJD=")).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid base64 string: invalid length");
+    let error =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("+_8=")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid base64 string: invalid character `_` at position 1"
+    );
+    let error =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("QUJ=")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid base64 string: invalid character `J` at position 2"
+    );
+    let decoded =
+        jayson::deserialize::<Base64, _, StandardError>(OwnedValue::from("QUI=")).unwrap();
+    assert_eq!(decoded, Base64(b"AB".to_vec()));
+    let error = jayson::deserialize::<Hex, _, StandardError>(OwnedValue::from("abc")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid hexadecimal string: odd number of digits"
+    );
+    let error = jayson::deserialize::<Hex, _, StandardError>(OwnedValue::from("0g")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid hexadecimal string: invalid character `g` at position 1"
+    );
+    let error =
+        jayson::deserialize::<Bytes, _, StandardError>(OwnedValue::from("abc")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Bytes or Sequence, found string \"abc\""
+    );
+}
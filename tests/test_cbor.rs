@@ -0,0 +1,137 @@
+#![cfg(feature = "cbor")]
+
+use ciborium::value::Value;
+use jayson::{DeserializeFromValue, StandardError, ValuePointer};
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Request {
+    id: u32,
+    offset: i64,
+    ratio: f32,
+    name: String,
+    payload: Vec<u8>,
+    tags: BTreeMap<String, bool>,
+}
+
+fn request() -> Value {
+    Value::Map(vec![
+        (Value::from("id"), Value::Tag(0, Box::new(Value::from(7)))),
+        (Value::from("offset"), Value::from(-3)),
+        (Value::from("ratio"), Value::Float(0.5)),
+        (Value::from("name"), Value::from("search")),
+        (Value::from("payload"), Value::Bytes(vec![0, 1, 255])),
+        (
+            Value::from("tags"),
+            Value::Map(vec![
+                (Value::from(12), Value::Bool(true)),
+                (Value::Bool(false), Value::Bool(false)),
+                (Value::Bytes(vec![1, 2]), Value::Bool(true)),
+            ]),
+        ),
+    ])
+}
+
+#[test]
+fn deserialize_cbor() {
+    let mut bytes = vec![];
+    ciborium::ser::into_writer(&request(), &mut bytes).unwrap();
+    let value: Value = ciborium::de::from_reader(bytes.as_slice()).unwrap();
+
+    let request = jayson::deserialize::<Request, _, StandardError>(value).unwrap();
+    assert_eq!(
+        request,
+        Request {
+            id: 7,
+            offset: -3,
+            ratio: 0.5,
+            name: "search".to_owned(),
+            payload: vec![0, 1, 255],
+            tags: [("12", true), ("false", false), ("[1, 2]", true)]
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v))
+                .collect(),
+        }
+    );
+}
+
+#[test]
+fn cbor_value_kinds() {
+    let error = jayson::deserialize::<u8, _, StandardError>(Value::from(-1)).unwrap_err();
+    assert_eq!(error.to_string(), "Cannot deserialize -1 into a u8");
+
+    let big = Value::Integer(ciborium::value::Integer::try_from(-(1i128 << 64)).unwrap());
+    let error = jayson::deserialize::<f64, _, StandardError>(big.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize -18446744073709551616 into a i64"
+    );
+    // also when nested
+    let value = Value::Array(vec![Value::from(1), Value::Tag(2, Box::new(big))]);
+    let error = jayson::deserialize::<Vec<f64>, _, StandardError>(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize -18446744073709551616 into a i64 at `/1`"
+    );
+}
+
+#[test]
+fn colliding_keys_are_rejected() {
+    let value = Value::Map(vec![
+        (Value::from(1), Value::Bool(true)),
+        (Value::from("1"), Value::Bool(false)),
+    ]);
+    let error = jayson::deserialize::<BTreeMap<String, bool>, _, StandardError>(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"1\" appears more than once in the map. at `/1`"
+    );
+}
+
+#[test]
+fn every_conversion_validates_the_value() {
+    let big = Value::Integer(ciborium::value::Integer::try_from(-(1i128 << 64)).unwrap());
+    let value = Value::Map(vec![(Value::from("x"), big)]);
+    let error = jayson::OwnedValue::from_value::<_, StandardError>(value.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize -18446744073709551616 into a i64 at `/x`"
+    );
+    let error = jayson::Layered::new()
+        .layer::<StandardError>("cbor", value)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize -18446744073709551616 into a i64 at `/x`"
+    );
+
+    let colliding = Value::Map(vec![
+        (Value::from(1), Value::Bool(true)),
+        (Value::from("1"), Value::Bool(false)),
+    ]);
+    let error = jayson::OwnedValue::from_value::<_, StandardError>(colliding.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"1\" appears more than once in the map. at `/1`"
+    );
+    let error = jayson::Layered::new()
+        .layer::<StandardError>("cbor", colliding)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"1\" appears more than once in the map. at `/1`"
+    );
+}
+
+#[test]
+fn resolve_pointers() {
+    // the tag of self-described CBOR is ignored
+    let value = Value::Tag(55799, Box::new(request()));
+    let resolve = |pointer: &str| pointer.parse::<ValuePointer>().unwrap().resolve(&value);
+    assert_eq!(resolve("/name"), Some(&Value::from("search")));
+    assert_eq!(resolve("$.tags[\"12\"]"), Some(&Value::Bool(true)));
+    assert_eq!(resolve("$.tags[\"[1, 2]\"]"), Some(&Value::Bool(true)));
+    assert_eq!(resolve("/tags/13"), None);
+    assert_eq!(resolve("/payload/0"), None);
+}
@@ -424,6 +424,40 @@ impl MergeWithError<NeverError> for MyError {
     }
 }
 
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[jayson(error = MyError, serde_compat)]
+struct SerdeCompat {
+    field_one: u8,
+    #[serde(rename = "two", alias = "deux")]
+    field_two: Option<String>,
+    #[serde(default = "create_default_u8")]
+    field_three: u8,
+    #[serde(skip)]
+    skipped: Vec<u8>,
+    inner: SerdeCompatEnum,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+#[serde(tag = "kind")]
+#[jayson(error = MyError, serde_compat)]
+enum SerdeCompatEnum {
+    #[serde(rename = "a", alias = "alpha")]
+    A,
+    #[serde(rename_all = "camelCase")]
+    B { some_field: bool },
+}
+
+#[derive(PartialEq, Eq, Debug, Deserialize, DeserializeFromValue)]
+#[jayson(error = MyError, serde_compat)]
+struct SerdeCompatOverride {
+    #[serde(rename = "serde_name")]
+    #[jayson(rename = "jayson_name")]
+    x: u8,
+    #[jayson(alias = "other")]
+    y: u8,
+}
+
 #[track_caller]
 fn compare_with_serde_roundtrip<T>(x: T)
 where
@@ -733,3 +767,249 @@ fn test_de() {
         },
     );
 }
+
+#[test]
+fn test_serde_compat() {
+    compare_with_serde::<SerdeCompat>(
+        r#"{
+            "fieldOne": 1,
+            "two": "hello",
+            "inner": { "kind": "a" }
+        }"#,
+    );
+    compare_with_serde::<SerdeCompat>(
+        r#"{
+            "fieldOne": 1,
+            "deux": "hello",
+            "fieldThree": 3,
+            "inner": { "kind": "B", "someField": true }
+        }"#,
+    );
+    compare_with_serde::<SerdeCompat>(
+        r#"{
+            "fieldOne": 1,
+            "inner": { "kind": "alpha" }
+        }"#,
+    );
+    // `skip` fields are not accepted as keys
+    compare_with_serde::<SerdeCompat>(
+        r#"{
+            "fieldOne": 1,
+            "skipped": [1],
+            "inner": { "kind": "a" }
+        }"#,
+    );
+    // `deny_unknown_fields`
+    compare_with_serde::<SerdeCompat>(
+        r#"{
+            "fieldOne": 1,
+            "field_two": "hello",
+            "inner": { "kind": "a" }
+        }"#,
+    );
+    compare_with_serde_roundtrip(SerdeCompat {
+        field_one: 1,
+        field_two: None,
+        field_three: 2,
+        skipped: vec![],
+        inner: SerdeCompatEnum::B { some_field: false },
+    });
+
+    // jayson attributes take precedence over serde attributes
+    assert_ok_matches::<SerdeCompatOverride, MyError>(
+        r#"{ "jayson_name": 1, "other": 2 }"#,
+        SerdeCompatOverride { x: 1, y: 2 },
+    );
+    assert_error_matches::<SerdeCompatOverride, MyError>(
+        r#"{ "serde_name": 1, "y": 2 }"#,
+        MyError::MissingField("jayson_name".to_owned()),
+    );
+}
+
+#[test]
+fn test_serde_compat_accepted_keys_include_aliases() {
+    assert_error_matches::<SerdeCompat, MyError>(
+        r#"{ "fieldOne": 1, "other": 2, "inner": { "kind": "a" } }"#,
+        MyError::UnknownKey {
+            key: "other".to_owned(),
+            accepted: vec![
+                "fieldOne".to_owned(),
+                "two".to_owned(),
+                "deux".to_owned(),
+                "fieldThree".to_owned(),
+                "inner".to_owned(),
+            ],
+        },
+    );
+}
+
+/// Checks that the `rename_all` rule gives the same keys as serde for the fields of a struct
+/// and the variants of an enum.
+macro_rules! test_serde_compat_rename_all {
+    ($test:ident, $rule:tt) => {
+        #[test]
+        fn $test() {
+            #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+            #[serde(rename_all = $rule, deny_unknown_fields)]
+            #[jayson(error = MyError, serde_compat)]
+            struct Struct {
+                first_field: u8,
+                field2_name: Enum,
+            }
+
+            #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+            #[serde(tag = "kind", rename_all = $rule)]
+            #[jayson(error = MyError, serde_compat)]
+            enum Enum {
+                FirstVariant,
+                HTTPVariant2,
+            }
+
+            compare_with_serde_roundtrip(Struct {
+                first_field: 1,
+                field2_name: Enum::FirstVariant,
+            });
+            compare_with_serde_roundtrip(Struct {
+                first_field: 1,
+                field2_name: Enum::HTTPVariant2,
+            });
+        }
+    };
+}
+
+test_serde_compat_rename_all!(test_serde_compat_rename_all_lowercase, "lowercase");
+test_serde_compat_rename_all!(test_serde_compat_rename_all_uppercase, "UPPERCASE");
+test_serde_compat_rename_all!(test_serde_compat_rename_all_pascal_case, "PascalCase");
+test_serde_compat_rename_all!(test_serde_compat_rename_all_camel_case, "camelCase");
+test_serde_compat_rename_all!(test_serde_compat_rename_all_snake_case, "snake_case");
+test_serde_compat_rename_all!(
+    test_serde_compat_rename_all_screaming_snake_case,
+    "SCREAMING_SNAKE_CASE"
+);
+test_serde_compat_rename_all!(test_serde_compat_rename_all_kebab_case, "kebab-case");
+test_serde_compat_rename_all!(
+    test_serde_compat_rename_all_screaming_kebab_case,
+    "SCREAMING-KEBAB-CASE"
+);
+
+#[derive(PartialEq, Eq, Debug, DeserializeFromValue)]
+#[jayson(error = MyError, rename_all = "SCREAMING-KEBAB-CASE")]
+struct RenameAllString {
+    some_field: u8,
+}
+
+#[test]
+fn test_rename_all_string() {
+    assert_ok_matches::<RenameAllString, MyError>(
+        r#"{ "SOME-FIELD": 1 }"#,
+        RenameAllString { some_field: 1 },
+    );
+}
+
+#[derive(PartialEq, Eq, Debug, DeserializeFromValue)]
+#[jayson(error = MyError, rename_all = camelCase)]
+struct JaysonCamelCase {
+    value_2x: u8,
+    server: JaysonCamelCaseEnum,
+}
+
+#[derive(PartialEq, Eq, Debug, DeserializeFromValue)]
+#[jayson(error = MyError, tag = "kind", rename_all = camelCase)]
+enum JaysonCamelCaseEnum {
+    HTTPServer,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+#[serde(rename_all = "camelCase")]
+#[jayson(error = MyError, serde_compat)]
+struct SerdeCamelCase {
+    value_2x: u8,
+    server: SerdeCamelCaseEnum,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, DeserializeFromValue)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+#[jayson(error = MyError, serde_compat)]
+enum SerdeCamelCaseEnum {
+    HTTPServer,
+}
+
+/// The `rename_all` jayson attribute splits acronyms and digits into words, while the
+/// `rename_all` serde attribute only lowercases the first letter of variants and removes
+/// the underscores of fields.
+#[test]
+fn test_rename_all_jayson_and_serde_rules() {
+    assert_ok_matches::<JaysonCamelCase, MyError>(
+        r#"{ "value2X": 1, "server": { "kind": "httpServer" } }"#,
+        JaysonCamelCase {
+            value_2x: 1,
+            server: JaysonCamelCaseEnum::HTTPServer,
+        },
+    );
+    assert_ok_matches::<SerdeCamelCase, MyError>(
+        r#"{ "value2x": 1, "server": { "kind": "hTTPServer" } }"#,
+        SerdeCamelCase {
+            value_2x: 1,
+            server: SerdeCamelCaseEnum::HTTPServer,
+        },
+    );
+    compare_with_serde_roundtrip(SerdeCamelCase {
+        value_2x: 1,
+        server: SerdeCamelCaseEnum::HTTPServer,
+    });
+}
+
+#[derive(PartialEq, Debug, DeserializeFromValue)]
+#[jayson(error = MyError)]
+struct WithTriple {
+    triple: (u8, String, bool),
+}
+
+#[test]
+fn test_triple_has_three_elements() {
+    assert_ok_matches::<WithTriple, MyError>(
+        r#"{ "triple": [1, "a", true] }"#,
+        WithTriple {
+            triple: (1, "a".to_owned(), true),
+        },
+    );
+    assert_error_matches::<WithTriple, MyError>(
+        r#"{ "triple": [1, "a"] }"#,
+        MyError::Unexpected(
+            "The sequence should have exactly 3 elements, but it has 2.".to_owned(),
+        ),
+    );
+}
+
+#[test]
+fn test_unknown_tag_value_location() {
+    // the error points to the tag, not to the map containing it
+    let json = serde_json::json!({
+        "x": "a",
+        "t1": { "sometag": "A" },
+        "t2": { "sometag": "C" },
+        "n": {},
+    });
+    let error = jayson::deserialize::<Example, _, jayson::StandardError>(json).unwrap_err();
+    assert_eq!(
+        error.location,
+        ValuePointerRef::Origin
+            .push_key("t2")
+            .push_key("sometag")
+            .to_owned()
+    );
+}
+
+#[test]
+fn test_unknown_tag_value() {
+    assert_error_matches::<Tag, MyError>(
+        r#"{ "sometag": "b" }"#,
+        MyError::Unexpected(
+            "Unknown tag value `b`: expected one of `A`, `B`. Did you mean `B`?".to_owned(),
+        ),
+    );
+    assert_error_matches::<Tag, MyError>(
+        r#"{ "sometag": "Other" }"#,
+        MyError::Unexpected("Unknown tag value `Other`: expected one of `A`, `B`.".to_owned()),
+    );
+}
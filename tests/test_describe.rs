@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+
+use jayson::{Description, DeserializeFromValue, StandardError, ValueKind};
+
+#[derive(DeserializeFromValue)]
+#[jayson(rename_all = camelCase, deny_unknown_fields)]
+struct Settings {
+    display_name: String,
+    #[jayson(alias = "colour")]
+    color: Option<String>,
+    #[jayson(default)]
+    font_size: u8,
+    #[jayson(skip)]
+    cache: Vec<u8>,
+    theme: Theme,
+    children: Vec<Settings>,
+}
+
+#[derive(DeserializeFromValue)]
+#[jayson(tag = "mode")]
+enum Theme {
+    Light,
+    #[jayson(alias = "Night")]
+    Dark {
+        contrast: f64,
+    },
+}
+
+fn keys(description: &Description) -> Vec<&'static str> {
+    description.known_keys()
+}
+
+#[test]
+fn describe_struct() {
+    let description = <Settings as DeserializeFromValue<StandardError>>::describe();
+    assert!(matches!(
+        description,
+        Description::Struct {
+            deny_unknown_fields: true,
+            ..
+        }
+    ));
+    assert_eq!(description.accepted_kinds(), Some(vec![ValueKind::Map]));
+    assert_eq!(
+        keys(&description),
+        [
+            "displayName",
+            "color",
+            "colour",
+            "fontSize",
+            "theme",
+            "children"
+        ]
+    );
+
+    let required = |key: &str| description.field(key, None).unwrap().required;
+    assert!(required("displayName"));
+    assert!(!required("colour"));
+    assert!(!required("fontSize"));
+    assert!(required("theme"));
+    assert!(description.field("cache", None).is_none());
+
+    let color = (description.field("color", None).unwrap().describe)();
+    assert_eq!(
+        color.accepted_kinds(),
+        Some(vec![ValueKind::Null, ValueKind::String])
+    );
+    let children = (description.field("children", None).unwrap().describe)();
+    assert_eq!(
+        children.accepted_kinds(),
+        Some(vec![ValueKind::Sequence, ValueKind::Bytes])
+    );
+}
+
+#[test]
+fn describe_enum() {
+    let description = (<Settings as DeserializeFromValue<StandardError>>::describe()
+        .field("theme", None)
+        .unwrap()
+        .describe)();
+    match &description {
+        Description::TaggedEnum { tag, variants } => {
+            assert_eq!(*tag, "mode");
+            let names = variants.iter().map(|v| v.name).collect::<Vec<_>>();
+            assert_eq!(names, ["Light", "Dark"]);
+        }
+        _ => panic!("expected a tagged enum"),
+    }
+    assert_eq!(keys(&description), ["mode", "contrast"]);
+    assert_eq!(description.variant("Night").unwrap().name, "Dark");
+    assert!(description.field("contrast", Some("Light")).is_none());
+    let contrast = description.field("contrast", Some("Dark")).unwrap();
+    assert!(contrast.required);
+    assert_eq!(
+        (contrast.describe)().accepted_kinds(),
+        Some(vec![
+            ValueKind::Float,
+            ValueKind::Integer,
+            ValueKind::NegativeInteger
+        ])
+    );
+}
+
+#[test]
+fn describe_unknown() {
+    let description = <jayson::OwnedValue as DeserializeFromValue<StandardError>>::describe();
+    assert!(matches!(description, Description::Unknown));
+    assert_eq!(description.accepted_kinds(), None);
+}
+
+#[test]
+fn describe_sequences() {
+    // a `Vec` can also be read from binary data, but not the sets
+    let vec = <Vec<u8> as DeserializeFromValue<StandardError>>::describe();
+    assert_eq!(
+        vec.accepted_kinds(),
+        Some(vec![ValueKind::Sequence, ValueKind::Bytes])
+    );
+    let set = <std::collections::BTreeSet<u8> as DeserializeFromValue<StandardError>>::describe();
+    assert_eq!(set.accepted_kinds(), Some(vec![ValueKind::Sequence]));
+}
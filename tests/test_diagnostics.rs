@@ -0,0 +1,122 @@
+#![cfg(feature = "diagnostics")]
+
+use jayson::diagnostics::{self, Diagnostic, Diagnostics};
+use jayson::{json5, DeserializeError, DeserializeFromValue, ValuePointerRef};
+
+#[derive(Debug, DeserializeFromValue)]
+struct Server {
+    #[allow(dead_code)]
+    host: String,
+    #[allow(dead_code)]
+    ports: Vec<u16>,
+    #[allow(dead_code)]
+    mode: Mode,
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(tag = "type")]
+enum Mode {
+    Primary,
+    Replica,
+}
+
+#[test]
+fn json5_spans_and_accumulated_errors() {
+    let text = "{\n  host: 'localhost',\n  ports: [80, -1, 'x'],\n  mode: { type: 'Replika' },\n}";
+    let errors =
+        jayson::deserialize::<Server, _, Diagnostics>(json5::from_str(text).unwrap()).unwrap_err();
+    assert_eq!(errors.diagnostics.len(), 3);
+    assert_eq!(
+        errors.location(),
+        Some(
+            ValuePointerRef::Origin
+                .push_key("ports")
+                .push_index(1)
+                .to_owned()
+        )
+    );
+    assert_eq!(
+        errors.to_string(),
+        "Cannot deserialize -1 into a u16 at `/ports/1`\n\
+         expected an integer, found string \"x\" at `/ports/2`\n\
+         unknown tag value `Replika` at `/mode/type`"
+    );
+    assert_eq!(
+        errors.render(text, "server.json5"),
+        "\
+error: Cannot deserialize -1 into a u16
+ --> server.json5:3:15
+  |
+3 |   ports: [80, -1, 'x'],
+  |               ^^ invalid value
+
+error: expected an integer, found string \"x\"
+ --> server.json5:3:19
+  |
+3 |   ports: [80, -1, 'x'],
+  |                   ^^^ expected an integer here
+
+error: unknown tag value `Replika`
+ --> server.json5:4:17
+  |
+4 |   mode: { type: 'Replika' },
+  |                 ^^^^^^^^^ unknown tag value `Replika`
+  |
+  = help: did you mean `Replica`? The accepted tag values are `Primary`, `Replica`.
+"
+    );
+}
+
+#[test]
+fn multi_line_values_are_underlined_on_their_first_line() {
+    let text = "{\n  \"host\": \"a\",\n  \"ports\": [],\n  \"mode\": {\n    \"kind\": 1\n  }\n}\n";
+    let value: serde_json::Value = serde_json::from_str(text).unwrap();
+    let errors = jayson::deserialize::<Server, _, Diagnostics>(value).unwrap_err();
+    assert_eq!(
+        errors.render(text, "server.json"),
+        "\
+error: missing field `type`
+ --> server.json:4:11
+  |
+4 |   \"mode\": {
+  |           ^ this map is missing the field `type`
+"
+    );
+}
+
+#[test]
+fn diagnostics_without_snippet() {
+    // the text cannot be parsed, so the errors cannot be located in it
+    let diagnostic = Diagnostic::new(
+        "expected a boolean, found null",
+        ValuePointerRef::Origin.push_key("a"),
+    )
+    .with_help("use `true` or `false`");
+    assert_eq!(
+        diagnostics::render(&[diagnostic], "{ a: ", "input"),
+        "\
+error: expected a boolean, found null
+ --> input
+  = at `/a`
+  = help: use `true` or `false`
+"
+    );
+}
+
+#[test]
+fn custom_diagnostics_and_line_numbers() {
+    // the gutter is as wide as the line number
+    let text = format!("[{}\n  true]", "\n".repeat(11));
+    let diagnostic = Diagnostic::new("not allowed", ValuePointerRef::Origin.push_index(0))
+        .with_label("this value");
+    assert_eq!(
+        diagnostics::render(&[diagnostic], &text, "list.json"),
+        "\
+error: not allowed
+  --> list.json:13:3
+   |
+13 |   true]
+   |   ^^^^ this value
+"
+    );
+}
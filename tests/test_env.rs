@@ -0,0 +1,81 @@
+use jayson::env::Env;
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+struct Config {
+    name: String,
+    debug: bool,
+    db: Db,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+struct Db {
+    pool_size: u32,
+    url: Option<String>,
+}
+
+#[test]
+fn deserialize_env() {
+    let env = Env::new().prefix("APP");
+    let value = env.read_vars([
+        ("APP__NAME", "42"),
+        ("APP__DEBUG", "true"),
+        ("APP__DB__POOL_SIZE", "10"),
+        ("APPLICATION__NAME", "other"),
+        ("PATH", "/usr/bin"),
+    ]);
+    let config = jayson::deserialize::<Config, _, StandardError>(value).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            name: "42".to_owned(),
+            debug: true,
+            db: Db {
+                pool_size: 10,
+                url: None
+            }
+        }
+    );
+}
+
+#[test]
+fn custom_separator() {
+    let env = Env::new().prefix("APP").separator("_");
+    let value = env.read_vars([("APP_DB_URL", "x"), ("APP_DEBUG", "false")]);
+    let expected: jayson::OwnedMap = [
+        (
+            "db",
+            jayson::OwnedValue::Map([("url", "x")].into_iter().collect()),
+        ),
+        ("debug", jayson::OwnedValue::from("false")),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(value.0, jayson::OwnedValue::Map(expected));
+}
+
+#[test]
+fn nested_variables_take_precedence() {
+    let env = Env::new();
+    let first = env.read_vars([("DB", "1"), ("DB__URL", "x")]);
+    let second = env.read_vars([("DB__URL", "x"), ("DB", "1")]);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn error_points_to_variable() {
+    let env = Env::new().prefix("APP");
+    let value = env.read_vars([
+        ("APP__NAME", "a"),
+        ("APP__DEBUG", "true"),
+        ("APP__DB__POOL_SIZE", "many"),
+    ]);
+    let error = jayson::deserialize::<Config, _, StandardError>(value).unwrap_err();
+    assert_eq!(error.message, "expected Integer, found string \"many\"");
+    assert_eq!(env.variable_name(&error.location), "APP__DB__POOL_SIZE");
+
+    let value = env.read_vars([("APP__NAME", "a"), ("APP__DEBUG", "true")]);
+    let error = jayson::deserialize::<Config, _, StandardError>(value).unwrap_err();
+    assert_eq!(error.message, "missing field `db`");
+    assert_eq!(env.variable_name(&error.location), "APP");
+}
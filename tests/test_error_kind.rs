@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use jayson::{
+    DecodeError, DeserializeError, DeserializeFromValue, ErrorKind, Hex, MergeWithError, OwnedMap,
+    OwnedValue, ValueKind, ValuePointerRef,
+};
+
+/// An error rendering the error kinds in French, and keeping the other messages as they are
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrenchError(String);
+
+impl MergeWithError<FrenchError> for FrenchError {
+    fn merge(
+        _self_: Option<Self>,
+        other: FrenchError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for FrenchError {
+    fn location(&self) -> Option<jayson::ValuePointer> {
+        None
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        actual: ValueKind,
+        _accepted: &[ValueKind],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("Type incorrect : {actual}")))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        field: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("Champ manquant : {field}")))
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        key: &str,
+        _accepted: &[&str],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("Clé inconnue : {key}")))
+    }
+    fn invalid_value(
+        _self_: Option<Self>,
+        kind: ErrorKind,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        let message = match kind {
+            ErrorKind::IntegerOutOfRange { value, ty } => {
+                format!("Impossible de convertir {value} en {ty}")
+            }
+            ErrorKind::InvalidMapKey { key, ty } => format!("La clé « {key} » n'est pas un {ty}"),
+            ErrorKind::IncorrectSequenceLength { expected, actual } => {
+                format!("{actual} éléments au lieu de {expected}")
+            }
+            ErrorKind::InvalidHex(DecodeError::InvalidCharacter {
+                character,
+                position,
+            }) => format!("Caractère « {character} » invalide en position {position}"),
+            kind => kind.to_string(),
+        };
+        Err(Self(message))
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        msg: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(msg.to_owned()))
+    }
+}
+
+#[track_caller]
+fn french<T: DeserializeFromValue<FrenchError>>(value: OwnedValue) -> String {
+    match jayson::deserialize::<T, _, FrenchError>(value) {
+        Ok(_) => panic!("the deserialization should fail"),
+        Err(FrenchError(message)) => message,
+    }
+}
+
+#[test]
+fn error_kinds_rendered_by_catalog() {
+    assert_eq!(
+        french::<u8>(OwnedValue::Integer(300)),
+        "Impossible de convertir 300 en u8"
+    );
+    assert_eq!(
+        french::<u32>(OwnedValue::NegativeInteger(-1)),
+        "Impossible de convertir -1 en u32"
+    );
+    assert_eq!(
+        french::<i8>(OwnedValue::NegativeInteger(-129)),
+        "Impossible de convertir -129 en i8"
+    );
+
+    let map = OwnedMap::from_iter([("deux", 2u64)]);
+    assert_eq!(
+        french::<BTreeMap<u8, u8>>(OwnedValue::Map(map)),
+        "La clé « deux » n'est pas un u8"
+    );
+
+    let seq = OwnedValue::Sequence(vec![OwnedValue::Integer(1), OwnedValue::Integer(2)]);
+    assert_eq!(french::<(u8, u8, u8)>(seq), "2 éléments au lieu de 3");
+
+    assert_eq!(
+        french::<Hex>(OwnedValue::String("0x".to_owned())),
+        "Caractère « x » invalide en position 1"
+    );
+}
+
+#[test]
+fn english_rendering() {
+    assert_eq!(
+        ErrorKind::IntegerOutOfRange {
+            value: -1,
+            ty: "u64"
+        }
+        .to_string(),
+        "Cannot deserialize -1 into a u64"
+    );
+    assert_eq!(
+        ErrorKind::InvalidMapKey { key: "a", ty: "u8" }.to_string(),
+        "The key \"a\" could not be deserialized into the key type `u8`."
+    );
+    assert_eq!(
+        ErrorKind::IncorrectSequenceLength {
+            expected: 2,
+            actual: 3
+        }
+        .to_string(),
+        "The sequence should have exactly 2 elements, but it has 3."
+    );
+    assert_eq!(
+        ErrorKind::UnrepresentableFloat {
+            value: f64::INFINITY,
+            format: "JSON"
+        }
+        .to_string(),
+        "The float inf is not representable in JSON"
+    );
+    // the error kinds not handled by the catalog keep the English message
+    assert_eq!(
+        french::<Hex>(OwnedValue::String("abc".to_owned())),
+        "Invalid hexadecimal string: odd number of digits"
+    );
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "json5")]
+
+use jayson::json5::{self, ParseError};
+use jayson::{
+    DeserializeError, DeserializeFromValue, IntoValue, MergeWithError, OwnedValue, Position, Span,
+    ValueKind, ValuePointer, ValuePointerRef,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct SpanError {
+    location: ValuePointer,
+    span: Option<Span>,
+}
+impl SpanError {
+    fn new(location: ValuePointerRef) -> Self {
+        Self {
+            location: location.to_owned(),
+            span: location.span().cloned(),
+        }
+    }
+}
+impl MergeWithError<SpanError> for SpanError {
+    fn merge(
+        _self_: Option<Self>,
+        other: SpanError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for SpanError {
+    fn location(&self) -> Option<ValuePointer> {
+        Some(self.location.clone())
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        _actual: ValueKind,
+        _accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        _field: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        _key: &str,
+        _accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        _msg: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(error = SpanError, tag = "kind")]
+enum Shape {
+    Circle,
+    Square,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(error = SpanError, deny_unknown_fields)]
+struct Config {
+    name: String,
+    port: u16,
+    ratio: f64,
+    hosts: Vec<String>,
+}
+
+#[test]
+fn parse_json5_syntax() {
+    let value = json5::from_str(
+        r#"
+        /* block
+           comment */
+        {
+            // line comment
+            name: 'it\'s "quoted"\
+ continued \x41\u00e9\ud83d\ude00',
+            $port: +0x1F,
+            "ratio": .5,
+            list: [1, -2, 3., 1e3, Infinity, -Infinity, null, true, false,],
+            'dup': 1,
+            dup: 2,
+        }
+        "#,
+    )
+    .unwrap();
+    let value = OwnedValue::from_value::<_, jayson::StandardError>(value).unwrap();
+    let list = vec![
+        OwnedValue::Integer(1),
+        OwnedValue::NegativeInteger(-2),
+        OwnedValue::Float(3.0),
+        OwnedValue::Float(1000.0),
+        OwnedValue::Float(f64::INFINITY),
+        OwnedValue::Float(f64::NEG_INFINITY),
+        OwnedValue::Null,
+        OwnedValue::Boolean(true),
+        OwnedValue::Boolean(false),
+    ];
+    let expected: jayson::OwnedMap = [
+        (
+            "name",
+            OwnedValue::from("it's \"quoted\" continued A\u{e9}\u{1f600}"),
+        ),
+        ("$port", OwnedValue::Integer(31)),
+        ("ratio", OwnedValue::Float(0.5)),
+        ("list", OwnedValue::Sequence(list)),
+        ("dup", OwnedValue::Integer(2)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(value, OwnedValue::Map(expected));
+}
+
+#[test]
+fn deserialization_errors_have_spans() {
+    let text = "{\n  name: 'a',\n  port: 70000,\n  ratio: 1,\n  hosts: ['x', 2],\n}";
+    let error =
+        jayson::deserialize::<Config, _, SpanError>(json5::from_str(text).unwrap()).unwrap_err();
+    assert_eq!(
+        error.location,
+        ValuePointerRef::Origin.push_key("port").to_owned()
+    );
+    assert_eq!(
+        error.span,
+        Some(Span::from(Position {
+            start: 23,
+            end: 28,
+            line: 3,
+            column: 9,
+        }))
+    );
+    assert_eq!(&text[23..28], "70000");
+
+    let text = "{ name: 'a', port: 1, ratio: 1, hosts: ['x', 2] }";
+    let error =
+        jayson::deserialize::<Config, _, SpanError>(json5::from_str(text).unwrap()).unwrap_err();
+    let position = error.span.unwrap().position.unwrap();
+    assert_eq!((position.line, position.column), (1, 46));
+}
+
+#[test]
+fn unknown_variant_error_points_to_the_tag() {
+    let text = "{ kind: 'Triangle' }";
+    let error =
+        jayson::deserialize::<Shape, _, SpanError>(json5::from_str(text).unwrap()).unwrap_err();
+    assert_eq!(
+        error.location,
+        ValuePointerRef::Origin.push_key("kind").to_owned()
+    );
+    let position = error.span.unwrap().position.unwrap();
+    assert_eq!(&text[position.start..position.end], "'Triangle'");
+}
+
+#[test]
+fn resolve_error_location() {
+    let text = "{ name: 'a', port: 70000, port: 80000, ratio: 1, hosts: [] }";
+    let value = json5::from_str(text).unwrap();
+    let error = jayson::deserialize::<Config, _, SpanError>(value.clone()).unwrap_err();
+    let offending = error.location.resolve(&value).unwrap();
+    // the last value of a repeated key is the one that was deserialized
+    let span = offending.span().unwrap();
+    let position = span.position.unwrap();
+    assert_eq!(&text[position.start..position.end], "80000");
+    assert_eq!(Some(span), error.span);
+}
+
+#[track_caller]
+fn parse_error(text: &str) -> (String, usize, usize) {
+    let ParseError {
+        message,
+        line,
+        column,
+        ..
+    } = json5::from_str(text).unwrap_err();
+    (message, line, column)
+}
+
+#[test]
+fn syntax_errors() {
+    assert_eq!(
+        parse_error("{\n  a: 1\n  b: 2\n}"),
+        ("unexpected character `b`".to_owned(), 3, 3)
+    );
+    assert_eq!(
+        parse_error("{\r\n  'é': 'ü', b 2 }"),
+        ("unexpected character `2`".to_owned(), 2, 15)
+    );
+    assert_eq!(
+        parse_error("[1, 2"),
+        ("unexpected end of input".to_owned(), 1, 6)
+    );
+    assert_eq!(
+        parse_error("{ a: 'x }"),
+        ("unterminated string".to_owned(), 1, 6)
+    );
+    assert_eq!(
+        parse_error("/* x"),
+        ("unterminated comment".to_owned(), 1, 1)
+    );
+    assert_eq!(
+        parse_error("{ a: 012 }"),
+        ("invalid number `012`".to_owned(), 1, 6)
+    );
+    assert_eq!(
+        parse_error("{ a: yes }"),
+        ("unexpected identifier `yes`".to_owned(), 1, 6)
+    );
+    assert_eq!(
+        parse_error("1 2"),
+        ("unexpected character `2` after the value".to_owned(), 1, 3)
+    );
+    assert_eq!(
+        parse_error(&"[".repeat(200)),
+        ("recursion limit exceeded".to_owned(), 1, 129)
+    );
+    assert_eq!(
+        json5::from_str("[,]").unwrap_err().to_string(),
+        "unexpected character `,` at line 1 column 2"
+    );
+}
@@ -0,0 +1,221 @@
+use jayson::env::Env;
+use jayson::{args, Layered, OwnedMap, OwnedValue};
+use jayson::{
+    DeserializeError, DeserializeFromValue, MergeWithError, ValueKind, ValuePointer,
+    ValuePointerRef,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MyError {
+    location: ValuePointer,
+    source: Option<String>,
+    message: String,
+}
+impl MyError {
+    fn new(location: ValuePointerRef, message: impl Into<String>) -> Self {
+        Self {
+            location: location.to_owned(),
+            source: location
+                .span()
+                .and_then(|span| span.source.as_deref())
+                .map(ToOwned::to_owned),
+            message: message.into(),
+        }
+    }
+}
+impl MergeWithError<MyError> for MyError {
+    fn merge(
+        _self_: Option<Self>,
+        other: MyError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for MyError {
+    fn location(&self) -> Option<ValuePointer> {
+        Some(self.location.clone())
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        actual: ValueKind,
+        _accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location, format!("incorrect {actual:?}")))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        field: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location, format!("missing {field}")))
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        key: &str,
+        _accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location, format!("unknown {key}")))
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        msg: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location, msg))
+    }
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(error = MyError, deny_unknown_fields)]
+struct Config {
+    port: u16,
+    hosts: Vec<String>,
+    db: Db,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(error = MyError, deny_unknown_fields)]
+struct Db {
+    url: String,
+    pool_size: u32,
+}
+
+fn defaults() -> OwnedValue {
+    let db: OwnedMap = [
+        ("url", OwnedValue::from("localhost")),
+        ("pool_size", OwnedValue::from(4u64)),
+    ]
+    .into_iter()
+    .collect();
+    let mut map = OwnedMap::new();
+    map.insert("port", 7700u64);
+    map.insert("hosts", vec!["a", "b"]);
+    map.insert("db", db);
+    OwnedValue::Map(map)
+}
+
+#[test]
+fn deep_merge_and_last_wins() {
+    let value = Layered::new()
+        .layer::<MyError>("defaults", defaults())
+        .unwrap()
+        .layer::<MyError>(
+            "env",
+            Env::new()
+                .prefix("APP")
+                .read_vars([("APP__DB__POOL_SIZE", "8"), ("APP__PORT", "1")]),
+        )
+        .unwrap()
+        .layer::<MyError>(
+            "args",
+            args::parse(["--port", "80", "--hosts", "c", "--hosts", "d"]).unwrap(),
+        )
+        .unwrap();
+    let config = jayson::deserialize::<Config, _, MyError>(value).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            port: 80,
+            hosts: vec!["c".to_owned(), "d".to_owned()],
+            db: Db {
+                url: "localhost".to_owned(),
+                pool_size: 8,
+            }
+        }
+    );
+}
+
+#[test]
+fn errors_report_the_source_of_the_value() {
+    let env = Env::new().prefix("APP");
+    let value = Layered::new()
+        .layer::<MyError>("defaults", defaults())
+        .unwrap()
+        .layer::<MyError>("env", env.read_vars([("APP__DB__POOL_SIZE", "-8")]))
+        .unwrap();
+    let error = jayson::deserialize::<Config, _, MyError>(value).unwrap_err();
+    assert_eq!(error.source.as_deref(), Some("env"));
+    assert_eq!(env.variable_name(&error.location), "APP__DB__POOL_SIZE");
+
+    let mut value = Layered::new();
+    value.push_layer::<MyError>("defaults", defaults()).unwrap();
+    value
+        .push_layer::<MyError>("args", args::parse(["--db.url=x", "--db.extra=1"]).unwrap())
+        .unwrap();
+    let error = jayson::deserialize::<Config, _, MyError>(value).unwrap_err();
+    assert_eq!(error.message, "unknown extra");
+    assert_eq!(error.source.as_deref(), Some("args"));
+
+    let mut defaults = defaults();
+    if let OwnedValue::Map(map) = &mut defaults {
+        map.insert("port", "not a port");
+    }
+    let value = Layered::new()
+        .layer::<MyError>("defaults", defaults)
+        .unwrap()
+        .layer::<MyError>("args", args::parse(["--db.url=x"]).unwrap())
+        .unwrap();
+    let error = jayson::deserialize::<Config, _, MyError>(value).unwrap_err();
+    assert_eq!(error.message, "incorrect String");
+    assert_eq!(error.source.as_deref(), Some("defaults"));
+}
+
+#[test]
+fn empty_layered_value_is_an_empty_map() {
+    let error = jayson::deserialize::<Config, _, MyError>(Layered::new()).unwrap_err();
+    assert_eq!(error.message, "missing port");
+    assert_eq!(error.source, None);
+}
+
+#[test]
+fn strings_are_only_parsed_in_the_layers_enabling_it() {
+    // the defaults are not a `ParseStrings` value, so "80" is not an integer there
+    let mut defaults = defaults();
+    if let OwnedValue::Map(map) = &mut defaults {
+        map.insert("port", "80");
+    }
+    let value = Layered::new()
+        .layer::<MyError>("defaults", defaults.clone())
+        .unwrap();
+    let error = jayson::deserialize::<Config, _, MyError>(value).unwrap_err();
+    assert_eq!(error.message, "incorrect String");
+    assert_eq!(error.source.as_deref(), Some("defaults"));
+
+    let env = Env::new().prefix("APP");
+    let value = Layered::new()
+        .layer::<MyError>("defaults", defaults)
+        .unwrap()
+        .layer::<MyError>(
+            "env",
+            env.read_vars([("APP__PORT", "81"), ("APP__HOSTS", "h")]),
+        )
+        .unwrap();
+    let config = jayson::deserialize::<Config, _, MyError>(value).unwrap();
+    assert_eq!(config.port, 81);
+    assert_eq!(config.hosts, ["h"]);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn invalid_layers_are_rejected() {
+    let yaml: serde_yaml::Value = serde_yaml::from_str("db: { 1: a, '1': b }").unwrap();
+    let mut value = Layered::new()
+        .layer::<MyError>("defaults", defaults())
+        .unwrap();
+    let error = value.push_layer::<MyError>("file", yaml).unwrap_err();
+    assert_eq!(
+        error.message,
+        "The key \"1\" appears more than once in the map."
+    );
+    assert_eq!(error.location.to_string(), "/db/1");
+    // the value is left unchanged
+    assert_eq!(
+        value,
+        Layered::new()
+            .layer::<MyError>("defaults", defaults())
+            .unwrap()
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "msgpack")]
+
+use jayson::{DeserializeFromValue, OwnedValue, StandardError, ValuePointer};
+use rmpv::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Request {
+    id: u32,
+    offset: i64,
+    ratio: f32,
+    name: String,
+    payload: Vec<u8>,
+    tags: BTreeMap<String, bool>,
+}
+
+fn request() -> Value {
+    Value::Map(vec![
+        (Value::from("id"), Value::from(7)),
+        (Value::from("offset"), Value::from(-3)),
+        (Value::from("ratio"), Value::F32(0.5)),
+        (Value::from("name"), Value::from("search")),
+        (Value::from("payload"), Value::Binary(vec![0, 1, 255])),
+        (
+            Value::from("tags"),
+            Value::Map(vec![
+                (Value::from(12), Value::Boolean(true)),
+                (Value::Nil, Value::Boolean(false)),
+                (
+                    Value::Array(vec![Value::from(1), Value::from("a")]),
+                    Value::Boolean(true),
+                ),
+            ]),
+        ),
+    ])
+}
+
+#[test]
+fn deserialize_msgpack() {
+    let mut bytes = vec![];
+    rmpv::encode::write_value(&mut bytes, &request()).unwrap();
+    let value = rmpv::decode::read_value(&mut bytes.as_slice()).unwrap();
+
+    let request = jayson::deserialize::<Request, _, StandardError>(value).unwrap();
+    assert_eq!(
+        request,
+        Request {
+            id: 7,
+            offset: -3,
+            ratio: 0.5,
+            name: "search".to_owned(),
+            payload: vec![0, 1, 255],
+            tags: [("12", true), ("null", false), ("[1, a]", true)]
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v))
+                .collect(),
+        }
+    );
+}
+
+#[test]
+fn msgpack_extension() {
+    let value = Value::Ext(-2, vec![1, 2]);
+    let value = jayson::deserialize::<OwnedValue, _, StandardError>(value).unwrap();
+    let expected: jayson::OwnedMap = [
+        ("type", OwnedValue::from(-2)),
+        ("data", OwnedValue::Bytes(vec![1, 2])),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(value, OwnedValue::Map(expected));
+}
+
+#[test]
+fn msgpack_value_kinds() {
+    let error = jayson::deserialize::<u8, _, StandardError>(Value::from(-1)).unwrap_err();
+    assert_eq!(error.to_string(), "Cannot deserialize -1 into a u8");
+
+    let error =
+        jayson::deserialize::<String, _, StandardError>(Value::Binary(vec![1])).unwrap_err();
+    assert_eq!(error.to_string(), "expected String, found 1 byte");
+}
+
+#[test]
+fn colliding_keys_are_rejected() {
+    let value = Value::Map(vec![
+        (Value::from("a"), Value::Map(vec![])),
+        (
+            Value::from("b"),
+            Value::Map(vec![
+                (Value::Boolean(true), Value::from(1)),
+                (Value::from("true"), Value::from(2)),
+            ]),
+        ),
+    ]);
+    let error = jayson::deserialize::<OwnedValue, _, StandardError>(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"true\" appears more than once in the map. at `/b/true`"
+    );
+}
+
+#[test]
+fn resolve_pointers() {
+    let value = request();
+    let resolve = |pointer: &str| pointer.parse::<ValuePointer>().unwrap().resolve(&value);
+    assert_eq!(resolve("/name"), Some(&Value::from("search")));
+    assert_eq!(resolve("$.tags[\"null\"]"), Some(&Value::Boolean(false)));
+    assert_eq!(resolve("$.tags[\"[1, a]\"]"), Some(&Value::Boolean(true)));
+    assert_eq!(resolve("/missing"), None);
+}
@@ -0,0 +1,110 @@
+#![cfg(all(feature = "openapi", feature = "serde_json"))]
+#![allow(dead_code)]
+
+use jayson::{openapi, JsonSchema};
+use serde_json::{json, Value};
+
+/// A user of the application.
+///
+/// Users are created by the administrators.
+#[derive(JsonSchema)]
+#[jayson(rename_all = camelCase)]
+struct User {
+    /// The name displayed in the interface
+    display_name: String,
+    role: Role,
+}
+
+#[derive(JsonSchema)]
+#[jayson(tag = "type")]
+enum Role {
+    /// An administrator, who can manage the users
+    Admin,
+    Member {
+        /// The team of the member
+        team: String,
+    },
+}
+
+#[test]
+fn components() {
+    assert_eq!(
+        Value::from(openapi::components_for::<User>()),
+        json!({
+            "schemas": {
+                "User": {
+                    "type": "object",
+                    "properties": {
+                        "displayName": {
+                            "type": "string",
+                            "description": "The name displayed in the interface",
+                        },
+                        "role": { "$ref": "#/components/schemas/Role" },
+                    },
+                    "required": ["displayName", "role"],
+                    "description": "A user of the application.\n\nUsers are created by the administrators.",
+                },
+                "Role": {
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/Role.Admin" },
+                        { "$ref": "#/components/schemas/Role.Member" },
+                    ],
+                    "discriminator": {
+                        "propertyName": "type",
+                        "mapping": {
+                            "Admin": "#/components/schemas/Role.Admin",
+                            "Member": "#/components/schemas/Role.Member",
+                        },
+                    },
+                },
+                "Role.Admin": {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": "Admin" },
+                    },
+                    "required": ["type"],
+                    "description": "An administrator, who can manage the users",
+                },
+                "Role.Member": {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": "Member" },
+                        "team": { "type": "string", "description": "The team of the member" },
+                    },
+                    "required": ["type", "team"],
+                },
+            }
+        })
+    );
+}
+
+#[test]
+fn unnamed_types_are_inlined() {
+    let mut components = openapi::Components::new();
+    let schema = components.add::<Vec<User>>();
+    assert_eq!(
+        Value::from(schema),
+        json!({ "type": "array", "items": { "$ref": "#/components/schemas/User" } })
+    );
+    assert_eq!(components.schemas().len(), 4);
+}
+
+#[derive(JsonSchema)]
+#[jayson(tag = "type")]
+enum Aliased {
+    #[jayson(alias = "b", alias = "c")]
+    A,
+}
+
+#[test]
+fn aliases_are_in_the_discriminator_mapping() {
+    let components = Value::from(openapi::components_for::<Aliased>());
+    assert_eq!(
+        components["schemas"]["Aliased"]["discriminator"]["mapping"],
+        json!({
+            "A": "#/components/schemas/Aliased.A",
+            "b": "#/components/schemas/Aliased.A",
+            "c": "#/components/schemas/Aliased.A",
+        })
+    );
+}
@@ -0,0 +1,96 @@
+use jayson::{DeserializeFromValue, OwnedMap, OwnedValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(tag = "type")]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    Polygon {
+        points: Vec<(i32, i32)>,
+        name: Option<String>,
+    },
+}
+
+#[test]
+fn deserialize_hand_built_value() {
+    let map: OwnedMap = [
+        ("type", OwnedValue::from("Polygon")),
+        ("points", vec![vec![0, 0], vec![-1, 2]].into()),
+        ("name", None::<String>.into()),
+    ]
+    .into_iter()
+    .collect();
+
+    let shape = jayson::deserialize::<Shape, _, StandardError>(OwnedValue::Map(map)).unwrap();
+    assert_eq!(
+        shape,
+        Shape::Polygon {
+            points: vec![(0, 0), (-1, 2)],
+            name: None
+        }
+    );
+
+    let mut map = OwnedMap::new();
+    map.insert("type", "Circle");
+    let error = jayson::deserialize::<Shape, _, StandardError>(OwnedValue::Map(map)).unwrap_err();
+    assert_eq!(error.to_string(), "missing field `radius`");
+}
+
+#[test]
+fn map_preserves_insertion_order() {
+    let mut map = OwnedMap::new();
+    assert!(map.insert("z", 1).is_none());
+    assert!(map.insert("a", 2).is_none());
+    assert!(map.insert("m", 3).is_none());
+    assert_eq!(map.insert("a", 4), Some(OwnedValue::Integer(2)));
+    assert_eq!(map.remove("z"), Some(OwnedValue::Integer(1)));
+
+    let keys = map.iter().map(|(k, _)| k).collect::<Vec<_>>();
+    assert_eq!(keys, ["a", "m"]);
+    assert_eq!(map.get("a"), Some(&OwnedValue::Integer(4)));
+}
+
+#[test]
+fn map_order_survives_many_removals() {
+    let mut map = (0..100).map(|i| (i.to_string(), i)).collect::<OwnedMap>();
+    for i in (0..100).filter(|i| i % 10 != 0) {
+        assert_eq!(map.remove(&i.to_string()), Some(OwnedValue::Integer(i)));
+    }
+    assert_eq!(map.remove("1"), None);
+    map.insert("last", 100);
+    map.insert("0", -1);
+
+    let keys = map.iter().map(|(k, _)| k).collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        ["0", "10", "20", "30", "40", "50", "60", "70", "80", "90", "last"]
+    );
+    assert_eq!(map.len(), 11);
+    assert_eq!(map.get("0"), Some(&OwnedValue::NegativeInteger(-1)));
+    assert_eq!(map.get("90"), Some(&OwnedValue::Integer(90)));
+
+    let same = keys.iter().map(|k| (*k, map.get(k).unwrap().clone()));
+    assert_eq!(map, same.collect::<OwnedMap>());
+}
+
+#[test]
+fn convert_from_other_values() {
+    let json = serde_json::json!({ "b": [1, -1, 0.5, null, true], "a": "x" });
+    let value = jayson::deserialize::<OwnedValue, _, StandardError>(json.clone()).unwrap();
+
+    let mut expected = OwnedMap::new();
+    expected.insert("a", "x");
+    expected.insert(
+        "b",
+        OwnedValue::Sequence(vec![
+            OwnedValue::Integer(1),
+            OwnedValue::NegativeInteger(-1),
+            OwnedValue::Float(0.5),
+            OwnedValue::Null,
+            OwnedValue::Boolean(true),
+        ]),
+    );
+    assert_eq!(value, OwnedValue::Map(expected));
+    assert_eq!(serde_json::Value::from(value), json);
+}
@@ -0,0 +1,271 @@
+use jayson::{
+    OwnedMap, OwnedValue, ValuePointer, ValuePointerComponent, ValuePointerComponentRef,
+    ValuePointerRef,
+};
+
+fn pointer(components: &[ValuePointerComponent]) -> ValuePointer {
+    ValuePointer {
+        path: components.to_vec(),
+    }
+}
+
+fn key(key: &str) -> ValuePointerComponent {
+    ValuePointerComponent::Key(key.to_owned())
+}
+
+#[test]
+fn display_is_ordered_from_the_origin() {
+    let origin = ValuePointerRef::Origin;
+    let a = origin.push_key("a");
+    let b = a.push_key("b");
+    let location = b.push_index(3);
+    assert_eq!(location.to_owned().to_string(), "/a/b/3");
+    assert_eq!(ValuePointer::default().to_string(), "");
+}
+
+#[test]
+fn json_pointer() {
+    let p = pointer(&[
+        key("a/b"),
+        key("m~n"),
+        ValuePointerComponent::Index(0),
+        key(""),
+    ]);
+    assert_eq!(p.to_json_pointer(), "/a~1b/m~0n/0/");
+    assert_eq!(ValuePointer::from_json_pointer("/a~1b/m~0n/0/").unwrap(), p);
+
+    // the examples of RFC 6901
+    assert_eq!(
+        ValuePointer::from_json_pointer("/ ").unwrap(),
+        pointer(&[key(" ")])
+    );
+    assert_eq!(
+        ValuePointer::from_json_pointer("/~01").unwrap(),
+        pointer(&[key("~1")])
+    );
+    assert_eq!(
+        ValuePointer::from_json_pointer("/foo/0").unwrap(),
+        pointer(&[key("foo"), ValuePointerComponent::Index(0)])
+    );
+    // tokens with leading zeros are keys
+    assert_eq!(
+        ValuePointer::from_json_pointer("/01").unwrap(),
+        pointer(&[key("01")])
+    );
+
+    let error = ValuePointer::from_json_pointer("a").unwrap_err();
+    assert_eq!(error.to_string(), "expected `/` at offset 0");
+    let error = ValuePointer::from_json_pointer("/a/b~2").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected `0` or `1` after `~` at offset 5"
+    );
+}
+
+#[test]
+fn json_path() {
+    let p = pointer(&[
+        key("a"),
+        ValuePointerComponent::Index(0),
+        key("weird key"),
+        key("_b2"),
+        key("quote\"d"),
+        key("2"),
+    ]);
+    let path = r#"$.a[0]["weird key"]._b2["quote\"d"]["2"]"#;
+    assert_eq!(p.to_json_path(), path);
+    assert_eq!(ValuePointer::from_json_path(path).unwrap(), p);
+    assert_eq!(ValuePointer::default().to_json_path(), "$");
+    assert_eq!(
+        ValuePointer::from_json_path("$").unwrap(),
+        ValuePointer::default()
+    );
+
+    assert_eq!(
+        ValuePointer::from_json_path(r#"$['single'][12]["é\n"]"#).unwrap(),
+        pointer(&[key("single"), ValuePointerComponent::Index(12), key("é\n")])
+    );
+    assert_eq!(
+        ValuePointer::from_json_path(r#"$["😀"]"#).unwrap(),
+        pointer(&[key("😀")])
+    );
+
+    let error = ValuePointer::from_json_path("$.a[*]").unwrap_err();
+    assert_eq!(error.to_string(), "unexpected character `*` at offset 4");
+    let error = ValuePointer::from_json_path("$.a[0").unwrap_err();
+    assert_eq!(error.to_string(), "expected `]` at offset 5");
+    let error = ValuePointer::from_json_path(r#"$["a]"#).unwrap_err();
+    assert_eq!(error.to_string(), "unterminated string at offset 5");
+    let error = ValuePointer::from_json_path("$.a[01]").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "leading zeros are not allowed in an index at offset 4"
+    );
+    let error = ValuePointer::from_json_path(r#"$["\u+041"]"#).unwrap_err();
+    assert_eq!(error.to_string(), "invalid escape sequence at offset 3");
+}
+
+#[test]
+fn only_json_paths_keep_numeric_keys() {
+    let p = pointer(&[key("2"), ValuePointerComponent::Index(2), key("02")]);
+    assert_eq!(ValuePointer::from_json_path(&p.to_json_path()).unwrap(), p);
+    assert_eq!(p.to_json_pointer(), "/2/2/02");
+    assert_eq!(
+        ValuePointer::from_json_pointer(&p.to_json_pointer()).unwrap(),
+        pointer(&[
+            ValuePointerComponent::Index(2),
+            ValuePointerComponent::Index(2),
+            key("02")
+        ])
+    );
+}
+
+#[test]
+fn from_str() {
+    let p = pointer(&[key("a"), ValuePointerComponent::Index(1)]);
+    assert_eq!("/a/1".parse::<ValuePointer>().unwrap(), p);
+    assert_eq!("$.a[1]".parse::<ValuePointer>().unwrap(), p);
+    assert_eq!("".parse::<ValuePointer>().unwrap(), ValuePointer::default());
+
+    for s in ["/a/1", "/x~1y/~0", "", "/"] {
+        assert_eq!(s.parse::<ValuePointer>().unwrap().to_string(), s);
+    }
+}
+
+#[test]
+fn pointer_ref_helpers() {
+    let origin = ValuePointerRef::Origin;
+    assert_eq!(origin.depth(), 0);
+    assert!(origin.parent().is_none());
+    assert!(origin.last().is_none());
+    assert_eq!(origin.components().count(), 0);
+
+    let span = jayson::Span::default();
+    let a = origin.push_key("a");
+    let spanned = a.with_span(Some(&span));
+    let index = spanned.push_index(4);
+    let b = index.push_key("b");
+    let leaf = b.with_span(Some(&span));
+
+    assert_eq!(leaf.depth(), 3);
+    assert_eq!(leaf.last(), Some(ValuePointerComponentRef::Key("b")));
+    assert_eq!(leaf.parent().unwrap().to_owned().to_string(), "/a/4");
+    assert_eq!(
+        leaf.parent().unwrap().last(),
+        Some(ValuePointerComponentRef::Index(4))
+    );
+
+    let components = leaf.components();
+    assert_eq!(components.len(), 3);
+    assert_eq!(
+        components.collect::<Vec<_>>(),
+        [
+            ValuePointerComponentRef::Key("a"),
+            ValuePointerComponentRef::Index(4),
+            ValuePointerComponentRef::Key("b"),
+        ]
+    );
+    assert_eq!(
+        leaf.components().rev().collect::<Vec<_>>(),
+        [
+            ValuePointerComponentRef::Key("b"),
+            ValuePointerComponentRef::Index(4),
+            ValuePointerComponentRef::Key("a"),
+        ]
+    );
+    let mut components = leaf.components();
+    assert_eq!(components.next(), Some(ValuePointerComponentRef::Key("a")));
+    assert_eq!(
+        components.next_back(),
+        Some(ValuePointerComponentRef::Key("b"))
+    );
+    assert_eq!(components.next(), Some(ValuePointerComponentRef::Index(4)));
+    assert_eq!(components.next_back(), None);
+
+    let owned = leaf
+        .components()
+        .map(|component| component.to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(owned, leaf.to_owned().path);
+}
+
+/// Call `f` with a pointer made of `depth` indices, from 0 to `depth - 1`
+fn with_deep_pointer(depth: usize, f: impl FnOnce(ValuePointerRef)) {
+    fn push(pointer: ValuePointerRef, index: usize, depth: usize, f: impl FnOnce(ValuePointerRef)) {
+        if index == depth {
+            f(pointer)
+        } else {
+            push(pointer.push_index(index), index + 1, depth, f)
+        }
+    }
+    push(ValuePointerRef::Origin, 0, depth, f)
+}
+
+#[test]
+fn components_of_deep_pointers() {
+    with_deep_pointer(100, |pointer| {
+        let indices = |components: Vec<ValuePointerComponentRef>| {
+            components
+                .into_iter()
+                .map(|component| match component {
+                    ValuePointerComponentRef::Index(index) => index,
+                    ValuePointerComponentRef::Key(_) => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let forward = indices(pointer.components().collect());
+        assert_eq!(forward, (0..100).collect::<Vec<_>>());
+        let backward = indices(pointer.components().rev().collect());
+        assert_eq!(backward, (0..100).rev().collect::<Vec<_>>());
+
+        // alternate between both ends
+        let mut components = pointer.components();
+        let mut front = vec![];
+        let mut back = vec![];
+        while let Some(component) = components.next() {
+            front.push(component);
+            back.extend(components.next_back());
+            back.extend(components.next_back());
+        }
+        assert_eq!(indices(front), (0..34).collect::<Vec<_>>());
+        assert_eq!(indices(back), (34..100).rev().collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn resolve_owned_value() {
+    let value = OwnedValue::Map(OwnedMap::from_iter([
+        (
+            "users",
+            OwnedValue::Sequence(vec![OwnedValue::Map(OwnedMap::from_iter([("age", "ten")]))]),
+        ),
+        ("7", OwnedValue::Boolean(true)),
+    ]));
+    let resolve = |s: &str| s.parse::<ValuePointer>().unwrap().resolve(&value).cloned();
+
+    assert_eq!(resolve(""), Some(value.clone()));
+    assert_eq!(resolve("/users/0/age"), Some(OwnedValue::from("ten")));
+    assert_eq!(resolve("$.users[0].age"), Some(OwnedValue::from("ten")));
+    // an index selects the key with the same digits
+    assert_eq!(resolve("/7"), Some(OwnedValue::Boolean(true)));
+    assert_eq!(resolve("/users/1"), None);
+    assert_eq!(resolve("/users/0/name"), None);
+    assert_eq!(resolve("/users/0/age/0"), None);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn resolve_serde_json() {
+    use serde_json::json;
+
+    let value = json!({ "a": { "weird key": [1, { "b": null }] } });
+    let pointer: ValuePointer = r#"$.a["weird key"][1].b"#.parse().unwrap();
+    assert_eq!(pointer.resolve(&value), Some(&json!(null)));
+    assert_eq!(
+        "/a/weird key/0"
+            .parse::<ValuePointer>()
+            .unwrap()
+            .resolve(&value),
+        Some(&json!(1))
+    );
+}
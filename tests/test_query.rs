@@ -0,0 +1,136 @@
+#![cfg(all(feature = "query", feature = "serde_json"))]
+
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Search {
+    q: String,
+    limit: Option<u32>,
+    offset: i32,
+    ratio: f32,
+    crop: bool,
+    attributes: Vec<String>,
+}
+
+#[track_caller]
+fn from_query<T: DeserializeFromValue<StandardError>>(s: &str) -> Result<T, StandardError> {
+    jayson::deserialize(jayson::query::from_str(s))
+}
+
+#[track_caller]
+fn from_json<T: DeserializeFromValue<StandardError>>(s: &str) -> Result<T, StandardError> {
+    let value: serde_json::Value = serde_json::from_str(s).unwrap();
+    jayson::deserialize(value)
+}
+
+#[test]
+fn deserialize_query() {
+    let search = from_query::<Search>(
+        "q=12+rue%20du%20port&offset=-2&ratio=0.5&crop=false&attributes=a&attributes=b",
+    )
+    .unwrap();
+    assert_eq!(
+        search,
+        Search {
+            q: "12 rue du port".to_owned(),
+            limit: None,
+            offset: -2,
+            ratio: 0.5,
+            crop: false,
+            attributes: vec!["a".to_owned(), "b".to_owned()],
+        }
+    );
+
+    let search = from_query::<Search>(
+        "q=12&limit=3&offset=4&ratio=1&crop=true&attributes=a&attributes=b&attributes=c",
+    )
+    .unwrap();
+    assert_eq!(search.q, "12");
+    assert_eq!(search.limit, Some(3));
+    assert_eq!(search.ratio, 1.0);
+    assert_eq!(search.attributes.len(), 3);
+}
+
+#[test]
+fn query_errors_match_json_errors() {
+    let cases = [
+        (
+            "q=a&offset=0&ratio=0&crop=yes&attributes=a&attributes=b",
+            r#"{ "q": "a", "offset": 0, "ratio": 0, "crop": "yes", "attributes": ["a", "b"] }"#,
+        ),
+        (
+            "q=a&limit=-1&offset=0&ratio=0&crop=true&attributes=a&attributes=b",
+            r#"{ "q": "a", "limit": -1, "offset": 0, "ratio": 0, "crop": true, "attributes": ["a", "b"] }"#,
+        ),
+        (
+            "q=a&offset=1.5&ratio=0&crop=true&attributes=a&attributes=b",
+            r#"{ "q": "a", "offset": "1.5", "ratio": 0, "crop": true, "attributes": ["a", "b"] }"#,
+        ),
+        (
+            "q=a&offset=0&ratio=0&crop=true&attributes=a&attributes=b&other=1",
+            r#"{ "q": "a", "offset": 0, "ratio": 0, "crop": true, "attributes": ["a", "b"], "other": 1 }"#,
+        ),
+        (
+            "q=a&offset=0&ratio=0&crop=true",
+            r#"{ "q": "a", "offset": 0, "ratio": 0, "crop": true }"#,
+        ),
+    ];
+    for (query, json) in cases {
+        let query_error = from_query::<Search>(query).unwrap_err();
+        let json_error = from_json::<Search>(json).unwrap_err();
+        assert_eq!(query_error, json_error, "{query}");
+    }
+}
+
+#[test]
+fn single_values_are_sequences_of_one_element() {
+    let search = from_query::<Search>("q=a&offset=0&ratio=0&crop=true&attributes=a").unwrap();
+    assert_eq!(search.attributes, ["a"]);
+
+    let error = from_query::<Vec<u8>>("").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Sequence, found a map of 0 entries"
+    );
+}
+
+#[test]
+fn unparsable_strings_are_reported_as_strings() {
+    // a string is only parsed into the type requested by the implementation
+    let error = from_query::<Search>("q=a&offset=true&ratio=0&crop=1&attributes=a").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Integer or NegativeInteger, found string \"true\" at `/offset`"
+    );
+    let error = from_query::<Search>("q=a&offset=0&ratio=0&crop=1&attributes=a").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Boolean, found string \"1\" at `/crop`"
+    );
+
+    let search = from_query::<Search>("q=true&offset=-0&ratio=2&crop=true&attributes=1").unwrap();
+    assert_eq!(search.q, "true");
+    assert_eq!(search.offset, 0);
+    assert_eq!(search.ratio, 2.0);
+    assert_eq!(search.attributes, ["1"]);
+}
+
+#[test]
+fn strings_are_not_parsed_by_default() {
+    let value = jayson::OwnedValue::String("true".to_owned());
+    let error = jayson::deserialize::<bool, _, StandardError>(value).unwrap_err();
+    assert_eq!(error.to_string(), "expected Boolean, found string \"true\"");
+}
+
+#[test]
+fn from_pairs() {
+    let value = jayson::query::from_pairs([("a", "1"), ("b", "x"), ("a", "2"), ("a", "3")]);
+    let expected: jayson::OwnedMap = [
+        ("a", jayson::OwnedValue::from(vec!["1", "2", "3"])),
+        ("b", jayson::OwnedValue::from("x")),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(value.0, jayson::OwnedValue::Map(expected));
+}
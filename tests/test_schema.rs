@@ -0,0 +1,226 @@
+#![cfg(feature = "serde_json")]
+#![allow(dead_code)]
+
+use jayson::{schema_for, JsonSchema, SchemaGenerator};
+use serde_json::{json, Value};
+
+#[derive(JsonSchema)]
+#[jayson(rename_all = camelCase, deny_unknown_fields)]
+struct Config {
+    server_name: String,
+    #[jayson(rename = "listen")]
+    port: u16,
+    #[jayson(default)]
+    workers: u8,
+    timeout: Option<f64>,
+    #[jayson(skip)]
+    cache: Vec<u8>,
+    shape: Shape,
+}
+
+#[derive(JsonSchema)]
+#[jayson(tag = "kind", rename_all = lowercase)]
+enum Shape {
+    Circle { radius: f64 },
+    Point,
+}
+
+#[derive(JsonSchema)]
+struct Tree {
+    children: Vec<Tree>,
+}
+
+#[derive(JsonSchema)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+fn schema<T: JsonSchema>() -> Value {
+    Value::from(schema_for::<T>())
+}
+
+#[test]
+fn struct_schema() {
+    assert_eq!(
+        schema::<Config>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Config",
+            "type": "object",
+            "properties": {
+                "serverName": { "type": "string" },
+                "listen": { "type": "integer", "minimum": 0, "maximum": 65535 },
+                "workers": { "type": "integer", "minimum": 0, "maximum": 255 },
+                "timeout": { "anyOf": [{ "type": "number" }, { "type": "null" }] },
+                "shape": { "$ref": "#/$defs/Shape" },
+            },
+            "required": ["serverName", "listen", "shape"],
+            "additionalProperties": false,
+            "$defs": {
+                "Shape": {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "const": "circle" },
+                                "radius": { "type": "number" },
+                            },
+                            "required": ["kind", "radius"],
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "const": "point" },
+                            },
+                            "required": ["kind"],
+                        },
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn recursive_schema() {
+    assert_eq!(
+        schema::<Tree>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Tree",
+            "type": "object",
+            "properties": {
+                "children": { "type": "array", "items": { "$ref": "#/$defs/Tree" } },
+            },
+            "required": ["children"],
+            "$defs": {
+                "Tree": {
+                    "type": "object",
+                    "properties": {
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/Tree" } },
+                    },
+                    "required": ["children"],
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn generic_schema_is_inlined() {
+    let mut gen = SchemaGenerator::new();
+    let schema = Value::from(gen.subschema_for::<Wrapper<(bool, String)>>());
+    assert_eq!(
+        schema,
+        json!({
+            "type": "object",
+            "properties": {
+                "inner": {
+                    "type": "array",
+                    "prefixItems": [{ "type": "boolean" }, { "type": "string" }],
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+            },
+            "required": ["inner"],
+        })
+    );
+    assert!(gen.definitions().is_empty());
+}
+
+mod other {
+    use jayson::JsonSchema;
+
+    #[derive(JsonSchema)]
+    pub struct Tree {
+        pub leaf: bool,
+    }
+}
+
+#[derive(JsonSchema)]
+struct Forest {
+    first: Tree,
+    second: other::Tree,
+    third: Box<Tree>,
+}
+
+#[test]
+fn types_with_the_same_name_have_different_definitions() {
+    let schema = schema::<Forest>();
+    assert_eq!(
+        schema["properties"]["first"],
+        json!({ "$ref": "#/$defs/Tree" })
+    );
+    assert_eq!(
+        schema["properties"]["second"],
+        json!({ "$ref": "#/$defs/test_schema.other.Tree" })
+    );
+    assert_eq!(
+        schema["properties"]["third"],
+        json!({ "$ref": "#/$defs/Tree" })
+    );
+    assert_eq!(
+        schema["$defs"]["test_schema.other.Tree"]["properties"],
+        json!({ "leaf": { "type": "boolean" } })
+    );
+}
+
+#[derive(JsonSchema)]
+#[jayson(deny_unknown_fields, tag = "kind")]
+enum Aliased {
+    #[jayson(alias = "b")]
+    A {
+        #[jayson(alias = "colour")]
+        color: String,
+    },
+}
+
+#[derive(JsonSchema)]
+struct AliasedFields {
+    #[jayson(alias = "colour")]
+    color: String,
+    #[jayson(alias = "grey", alias = "gray")]
+    shade: Option<u8>,
+}
+
+#[test]
+fn aliases_are_mutually_exclusive_properties() {
+    assert_eq!(
+        schema::<Aliased>()["oneOf"][0],
+        json!({
+            "type": "object",
+            "properties": {
+                "kind": { "enum": ["A", "b"] },
+                "color": { "type": "string" },
+                "colour": { "type": "string" },
+            },
+            "required": ["kind"],
+            "oneOf": [{ "required": ["color"] }, { "required": ["colour"] }],
+            "additionalProperties": false,
+        })
+    );
+    let schema = schema::<AliasedFields>();
+    assert!(schema.get("required").is_none());
+    assert_eq!(
+        schema["allOf"],
+        json!([
+            { "oneOf": [{ "required": ["color"] }, { "required": ["colour"] }] },
+            {
+                "oneOf": [
+                    { "required": ["shade"] },
+                    { "required": ["grey"] },
+                    { "required": ["gray"] },
+                    {
+                        "not": {
+                            "anyOf": [
+                                { "required": ["shade"] },
+                                { "required": ["grey"] },
+                                { "required": ["gray"] },
+                            ]
+                        }
+                    },
+                ]
+            },
+        ])
+    );
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "serde")]
+
+use jayson::{serde::Serde, DeserializeFromValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Config {
+    name: String,
+    port: u16,
+    ratio: f32,
+    tags: Vec<String>,
+    limits: std::collections::BTreeMap<u8, i32>,
+    parent: Option<Box<Config>>,
+}
+
+#[track_caller]
+fn from_json<T>(j: &str) -> Result<T, StandardError>
+where
+    T: DeserializeFromValue<StandardError>,
+{
+    let mut deserializer = serde_json::Deserializer::from_str(j);
+    let value = jayson::serde::from_deserializer(&mut deserializer).unwrap();
+    jayson::deserialize(value)
+}
+
+#[test]
+fn deserialize_through_serde() {
+    let config = from_json::<Config>(
+        r#"{
+            "name": "a",
+            "port": 80,
+            "ratio": 0.5,
+            "tags": ["x", "y"],
+            "limits": { "1": -3 },
+            "parent": null
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            name: "a".to_owned(),
+            port: 80,
+            ratio: 0.5,
+            tags: vec!["x".to_owned(), "y".to_owned()],
+            limits: [(1, -3)].into_iter().collect(),
+            parent: None,
+        }
+    );
+}
+
+#[test]
+fn jayson_errors_through_serde() {
+    let error = from_json::<Config>(
+        r#"{ "name": "a", "port": true, "ratio": 1, "tags": [], "limits": {} }"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Integer, found boolean true at `/port`"
+    );
+
+    let error = from_json::<Config>(
+        r#"{ "name": "a", "port": 1, "ratio": 1, "tags": [], "limits": {}, "other": 0 }"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unknown key `other`: expected one of `name`, `port`, `ratio`, `tags`, `limits`, `parent`."
+    );
+}
+
+#[test]
+fn serde_errors_are_returned_by_the_deserializer() {
+    let mut deserializer = serde_json::Deserializer::from_str(r#"{ "name": "#);
+    assert!(jayson::serde::from_deserializer(&mut deserializer).is_err());
+}
+
+#[test]
+fn duplicate_keys_are_rejected() {
+    let mut deserializer =
+        serde_json::Deserializer::from_str(r#"{ "a": [{ "b": 1, "c": 2, "b": 3 }] }"#);
+    let error = jayson::serde::from_deserializer(&mut deserializer).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("The key \"b\" appears more than once in the map. at `/a/0/b`"));
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Shape {
+    name: String,
+    #[serde(deserialize_with = "jayson::serde::deserialize::<StandardError, _, _>")]
+    origin: Point,
+    points: Vec<Serde<Point, StandardError>>,
+}
+
+#[test]
+fn jayson_types_inside_serde_types() {
+    let shape: Shape = serde_json::from_str(
+        r#"{
+            "name": "triangle",
+            "origin": { "x": 0, "y": 0 },
+            "points": [{ "x": 1, "y": 0 }, { "x": 0, "y": 1 }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(shape.name, "triangle");
+    assert_eq!(shape.origin, Point { x: 0, y: 0 });
+    assert_eq!(*shape.points[1], Point { x: 0, y: 1 });
+}
+
+#[test]
+fn jayson_errors_inside_serde_types() {
+    let error = serde_json::from_str::<Shape>(
+        r#"{
+            "name": "triangle",
+            "origin": { "x": 0 },
+            "points": []
+        }"#,
+    )
+    .unwrap_err();
+    assert!(error.to_string().starts_with("missing field `y`"));
+}
+
+#[derive(Clone, Debug, PartialEq, DeserializeFromValue)]
+struct Size {
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Window {
+    #[serde(deserialize_with = "jayson::serde::deserialize_standard")]
+    size: Size,
+    min_size: Serde<Size>,
+}
+
+#[test]
+fn standard_error_inside_serde_types() {
+    let window: Window = serde_json::from_str(
+        r#"{ "size": { "width": 4, "height": 3 }, "min_size": { "width": 1, "height": 1 } }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        window.size,
+        Size {
+            width: 4,
+            height: 3
+        }
+    );
+    assert_eq!(
+        window.min_size.clone(),
+        Serde::new(Size {
+            width: 1,
+            height: 1
+        })
+    );
+
+    let error = serde_json::from_str::<Window>(r#"{ "size": { "width": 4, "height": true } }"#)
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("expected Integer, found boolean true at `/height`"));
+}
@@ -0,0 +1,128 @@
+use jayson::{
+    Base64, DeserializeFromValue, Hex, OwnedMap, OwnedValue, SerializeIntoValue, StandardError,
+};
+
+#[derive(Debug, PartialEq, DeserializeFromValue, SerializeIntoValue)]
+#[jayson(rename_all = camelCase, deny_unknown_fields)]
+struct Config {
+    server_name: String,
+    #[jayson(rename = "listen")]
+    port: u16,
+    tags: Vec<String>,
+    timeout: Option<f64>,
+    #[jayson(skip, default = 42)]
+    cache_size: usize,
+    #[jayson(skip)]
+    generation: u32,
+    shape: Shape,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue, SerializeIntoValue)]
+#[jayson(tag = "kind", rename_all = lowercase)]
+enum Shape {
+    Circle { radius: f64 },
+    Point,
+}
+
+#[derive(Debug, PartialEq, SerializeIntoValue)]
+struct Generic<T> {
+    inner: T,
+}
+
+fn config() -> Config {
+    Config {
+        server_name: "jayson".to_owned(),
+        port: 7700,
+        tags: vec!["a".to_owned(), "b".to_owned()],
+        timeout: None,
+        cache_size: 42,
+        generation: 0,
+        shape: Shape::Circle { radius: 2.5 },
+    }
+}
+
+#[test]
+fn serialize_struct() {
+    let mut shape = OwnedMap::new();
+    shape.insert("kind", "circle");
+    shape.insert("radius", 2.5);
+    let mut expected = OwnedMap::new();
+    expected.insert("serverName", "jayson");
+    expected.insert("listen", 7700u64);
+    expected.insert("tags", vec!["a", "b"]);
+    expected.insert("timeout", OwnedValue::Null);
+    expected.insert("shape", shape);
+
+    assert_eq!(config().serialize_into_value(), OwnedValue::Map(expected));
+}
+
+#[test]
+fn serialize_unit_variant() {
+    let mut expected = OwnedMap::new();
+    expected.insert("kind", "point");
+    assert_eq!(
+        Shape::Point.serialize_into_value(),
+        OwnedValue::Map(expected)
+    );
+}
+
+#[test]
+fn serialize_generic() {
+    let value = Generic { inner: -3i8 }.serialize_into_value();
+    let mut expected = OwnedMap::new();
+    expected.insert("inner", OwnedValue::NegativeInteger(-3));
+    assert_eq!(value, OwnedValue::Map(expected));
+}
+
+#[test]
+fn round_trip() {
+    let value = config().serialize_into_value();
+    let deserialized = jayson::deserialize::<Config, _, StandardError>(value).unwrap();
+    assert_eq!(deserialized, config());
+}
+
+#[test]
+fn skipped_fields_are_not_accepted() {
+    let mut value = match config().serialize_into_value() {
+        OwnedValue::Map(map) => map,
+        _ => unreachable!(),
+    };
+    value.insert("cacheSize", 3u64);
+    let err = jayson::deserialize::<Config, _, StandardError>(OwnedValue::Map(value)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown key `cacheSize`: expected one of `serverName`, `listen`, `tags`, `timeout`, `shape`."
+    );
+}
+
+#[test]
+fn serialize_bytes() {
+    assert_eq!(
+        Base64(b"hello".to_vec()).serialize_into_value(),
+        OwnedValue::from("aGVsbG8=")
+    );
+    assert_eq!(
+        Base64(b"hi!".to_vec()).serialize_into_value(),
+        OwnedValue::from("aGkh")
+    );
+    assert_eq!(
+        Hex(vec![0, 15, 255]).serialize_into_value(),
+        OwnedValue::from("000fff")
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serialize_to_json() {
+    let json = serde_json::Value::from(config().serialize_into_value());
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "serverName": "jayson",
+            "listen": 7700,
+            "tags": ["a", "b"],
+            "timeout": null,
+            "shape": { "kind": "circle", "radius": 2.5 },
+        })
+    );
+}
@@ -0,0 +1,213 @@
+use jayson::{
+    DeserializeError, DeserializeFromValue, IntoValue, MergeWithError, OwnedMap, OwnedValue,
+    ValueKind, ValuePointerRef, ValueSnapshot,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MyError(String);
+
+impl MergeWithError<MyError> for MyError {
+    fn merge(
+        _self_: Option<Self>,
+        other: MyError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for MyError {
+    fn location(&self) -> Option<jayson::ValuePointer> {
+        None
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        actual: ValueKind,
+        accepted: &[ValueKind],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("expected {accepted:?}, found {actual}")))
+    }
+    fn incorrect_value(
+        _self_: Option<Self>,
+        actual: ValueSnapshot,
+        accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!(
+            "expected {accepted:?}, found {actual} at `{}`",
+            location.to_owned()
+        )))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        field: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("missing field {field}")))
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        key: &str,
+        _accepted: &[&str],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(format!("unknown key {key}")))
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        msg: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(msg.to_owned()))
+    }
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(error = MyError)]
+struct Settings {
+    #[allow(dead_code)]
+    limit: u32,
+    #[allow(dead_code)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(error = MyError, tag = "type")]
+enum Action {
+    Stop,
+}
+
+#[track_caller]
+fn error<T: DeserializeFromValue<MyError> + std::fmt::Debug>(value: OwnedValue) -> String {
+    jayson::deserialize::<T, _, MyError>(value).unwrap_err().0
+}
+
+#[test]
+fn errors_receive_the_snapshot() {
+    let settings = OwnedMap::from_iter([
+        ("limit", OwnedValue::from("abc")),
+        ("tags", OwnedValue::from(Vec::<String>::new())),
+    ]);
+    assert_eq!(
+        error::<Settings>(OwnedValue::Map(settings)),
+        r#"expected [Integer], found string "abc" at `/limit`"#
+    );
+    let settings = OwnedMap::from_iter([
+        ("limit", OwnedValue::from(1)),
+        ("tags", OwnedValue::from(vec![true])),
+    ]);
+    assert_eq!(
+        error::<Settings>(OwnedValue::Map(settings)),
+        "expected [String], found boolean true at `/tags/0`"
+    );
+    assert_eq!(
+        error::<Settings>(OwnedValue::from(vec![1, 2])),
+        "expected [Map], found a sequence of 2 elements at ``"
+    );
+    assert_eq!(
+        error::<Action>(OwnedValue::Map(OwnedMap::from_iter([("type", 1.5)]))),
+        "expected [String], found float 1.5 at `/type`"
+    );
+}
+
+#[test]
+fn snapshots() {
+    let snapshot = |value: OwnedValue| ValueSnapshot::of(&value.into_value());
+
+    assert_eq!(snapshot(OwnedValue::Null).to_string(), "null");
+    assert_eq!(
+        snapshot(OwnedValue::NegativeInteger(-3)).to_string(),
+        "integer -3"
+    );
+    assert_eq!(snapshot(OwnedValue::Bytes(vec![1])).to_string(), "1 byte");
+    assert_eq!(
+        snapshot(OwnedValue::Map(OwnedMap::from_iter([("a", 1)]))).to_string(),
+        "a map of 1 entry"
+    );
+    assert_eq!(
+        snapshot(OwnedValue::from("quote\"d")).to_string(),
+        r#"string "quote\"d""#
+    );
+
+    let long = "é".repeat(100);
+    let long = snapshot(OwnedValue::from(long));
+    assert_eq!(long.kind(), ValueKind::String);
+    match &long {
+        ValueSnapshot::String { preview, truncated } => {
+            assert_eq!(preview.chars().count(), ValueSnapshot::MAX_PREVIEW_CHARS);
+            assert!(truncated);
+        }
+        _ => panic!("expected a string snapshot"),
+    }
+    assert!(long.to_string().ends_with("\"…"));
+
+    let exact = "a".repeat(ValueSnapshot::MAX_PREVIEW_CHARS);
+    assert_eq!(
+        snapshot(OwnedValue::from(exact.clone())),
+        ValueSnapshot::String {
+            preview: exact,
+            truncated: false
+        }
+    );
+}
+
+#[test]
+fn default_uses_the_kind() {
+    // the errors which do not override `incorrect_value` still receive the kind
+    #[derive(Debug, DeserializeFromValue)]
+    struct Flag {
+        #[allow(dead_code)]
+        on: bool,
+    }
+    let value = OwnedValue::Map(OwnedMap::from_iter([("on", "yes")]));
+    let error = jayson::deserialize::<Flag, _, KindError>(value).unwrap_err();
+    assert_eq!(error.0, ValueKind::String);
+}
+
+#[derive(Debug)]
+pub struct KindError(ValueKind);
+
+impl MergeWithError<KindError> for KindError {
+    fn merge(
+        _self_: Option<Self>,
+        other: KindError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for KindError {
+    fn location(&self) -> Option<jayson::ValuePointer> {
+        None
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        actual: ValueKind,
+        _accepted: &[ValueKind],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self(actual))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        _field: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        unreachable!()
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        _key: &str,
+        _accepted: &[&str],
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        unreachable!()
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        _msg: &str,
+        _location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        unreachable!()
+    }
+}
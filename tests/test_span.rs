@@ -0,0 +1,188 @@
+use jayson::{
+    DeserializeError, DeserializeFromValue, IntoValue, MergeWithError, Position, Span, Value,
+    ValueKind, ValuePointer, ValuePointerRef,
+};
+
+/// A minimal backend whose values know their position in the text `{"x":1,"y":[true,2]}`
+enum Node {
+    Boolean(bool),
+    Integer(u64),
+    Sequence(Vec<Spanned>),
+    Map(Vec<(String, Spanned)>),
+}
+struct Spanned {
+    node: Node,
+    span: Span,
+}
+
+fn spanned(node: Node, start: usize, end: usize) -> Spanned {
+    Spanned {
+        node,
+        span: Span::from(Position {
+            start,
+            end,
+            line: 1,
+            column: start + 1,
+        }),
+    }
+}
+
+impl IntoValue for Spanned {
+    type Sequence = Vec<Spanned>;
+    type Map = Vec<(String, Spanned)>;
+
+    fn kind(&self) -> ValueKind {
+        match self.node {
+            Node::Boolean(_) => ValueKind::Boolean,
+            Node::Integer(_) => ValueKind::Integer,
+            Node::Sequence(_) => ValueKind::Sequence,
+            Node::Map(_) => ValueKind::Map,
+        }
+    }
+    fn into_value(self) -> Value<Self> {
+        match self.node {
+            Node::Boolean(b) => Value::Boolean(b),
+            Node::Integer(x) => Value::Integer(x),
+            Node::Sequence(x) => Value::Sequence(x),
+            Node::Map(x) => Value::Map(x),
+        }
+    }
+    fn span(&self) -> Option<Span> {
+        Some(self.span.clone())
+    }
+}
+
+fn input() -> Spanned {
+    let y = spanned(
+        Node::Sequence(vec![
+            spanned(Node::Boolean(true), 13, 17),
+            spanned(Node::Integer(2), 18, 19),
+        ]),
+        12,
+        20,
+    );
+    let map = vec![
+        ("x".to_owned(), spanned(Node::Integer(1), 5, 6)),
+        ("y".to_owned(), y),
+    ];
+    spanned(Node::Map(map), 0, 21)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct SpanError {
+    location: ValuePointer,
+    span: Option<Span>,
+}
+impl SpanError {
+    fn new(location: ValuePointerRef) -> Self {
+        Self {
+            location: location.to_owned(),
+            span: location.span().cloned(),
+        }
+    }
+}
+impl MergeWithError<SpanError> for SpanError {
+    fn merge(
+        _self_: Option<Self>,
+        other: SpanError,
+        _merge_location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(other)
+    }
+}
+impl DeserializeError for SpanError {
+    fn location(&self) -> Option<ValuePointer> {
+        Some(self.location.clone())
+    }
+    fn incorrect_value_kind(
+        _self_: Option<Self>,
+        _actual: ValueKind,
+        _accepted: &[ValueKind],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn missing_field(
+        _self_: Option<Self>,
+        _field: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn unknown_key(
+        _self_: Option<Self>,
+        _key: &str,
+        _accepted: &[&str],
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+    fn unexpected(
+        _self_: Option<Self>,
+        _msg: &str,
+        location: ValuePointerRef,
+    ) -> Result<Self, Self> {
+        Err(Self::new(location))
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(error = SpanError)]
+struct Point {
+    x: u8,
+    y: Vec<bool>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, DeserializeFromValue)]
+#[jayson(error = SpanError)]
+struct MissingField {
+    z: u8,
+}
+
+#[test]
+fn error_span_of_nested_value() {
+    let error = jayson::deserialize::<Point, _, SpanError>(input()).unwrap_err();
+    assert_eq!(
+        error,
+        SpanError {
+            location: ValuePointerRef::Origin
+                .push_key("y")
+                .push_index(1)
+                .to_owned(),
+            span: Some(Span::from(Position {
+                start: 18,
+                end: 19,
+                line: 1,
+                column: 19,
+            })),
+        }
+    );
+}
+
+#[test]
+fn error_span_of_origin() {
+    let error = jayson::deserialize::<MissingField, _, SpanError>(input()).unwrap_err();
+    assert_eq!(error.location, ValuePointer::default());
+    let position = error.span.and_then(|span| span.position);
+    assert_eq!(position.map(|p| (p.start, p.end)), Some((0, 21)));
+}
+
+#[test]
+fn spans_are_not_part_of_the_owned_pointer() {
+    let span = Span::from(Position {
+        start: 0,
+        end: 1,
+        line: 1,
+        column: 1,
+    });
+    let origin = ValuePointerRef::Origin;
+    let key = origin.push_key("a");
+    let spanned = key.with_span(Some(&span));
+    assert_eq!(spanned.span(), Some(&span));
+    assert_eq!(spanned.to_owned(), key.to_owned());
+    assert_eq!(key.with_span(None).span(), None);
+    // the span of a parent is not inherited by its children
+    assert_eq!(spanned.push_index(0).span(), None);
+}
@@ -0,0 +1,33 @@
+use jayson::{did_you_mean, levenshtein_distance, unknown_message};
+
+#[test]
+fn distance() {
+    assert_eq!(levenshtein_distance("", ""), 0);
+    assert_eq!(levenshtein_distance("abc", ""), 3);
+    assert_eq!(levenshtein_distance("", "abc"), 3);
+    assert_eq!(levenshtein_distance("flaw", "lawn"), 2);
+    assert_eq!(levenshtein_distance("été", "ete"), 2);
+}
+
+#[test]
+fn suggestions() {
+    let accepted = ["displayedAttributes", "searchableAttributes", "stopWords"];
+    assert_eq!(
+        did_you_mean("displayAttributes", &accepted),
+        Some("displayedAttributes")
+    );
+    assert_eq!(did_you_mean("STOPWORDS", &accepted), Some("stopWords"));
+    assert_eq!(did_you_mean("synonyms", &accepted), None);
+    assert_eq!(did_you_mean("a", &[]), None);
+    // the closest candidate is preferred
+    assert_eq!(did_you_mean("abcd", &["abxx", "abcx"]), Some("abcx"));
+}
+
+#[test]
+fn messages() {
+    assert_eq!(unknown_message("key", "x", &[]), "Unknown key `x`.");
+    assert_eq!(
+        unknown_message("key", "sise", &["size", "color"]),
+        "Unknown key `sise`: expected one of `size`, `color`. Did you mean `size`?"
+    );
+}
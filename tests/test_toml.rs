@@ -0,0 +1,101 @@
+#![cfg(feature = "toml")]
+
+use jayson::{DeserializeFromValue, StandardError};
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Config {
+    title: String,
+    updated_at: String,
+    database: Database,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Database {
+    ports: Vec<u16>,
+    offset: i8,
+    ratio: f64,
+    enabled: bool,
+}
+
+#[track_caller]
+fn from_toml<T: DeserializeFromValue<StandardError>>(s: &str) -> Result<T, StandardError> {
+    let value: toml::Value = s.parse().unwrap();
+    jayson::deserialize(value)
+}
+
+#[test]
+fn deserialize_toml() {
+    let config = from_toml::<Config>(
+        r#"
+        title = "example"
+        updated_at = 1979-05-27T07:32:00Z
+
+        [database]
+        ports = [8000, 8001]
+        offset = -3
+        ratio = 1
+        enabled = true
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            title: "example".to_owned(),
+            updated_at: "1979-05-27T07:32:00Z".to_owned(),
+            database: Database {
+                ports: vec![8000, 8001],
+                offset: -3,
+                ratio: 1.0,
+                enabled: true,
+            }
+        }
+    );
+}
+
+#[test]
+fn toml_value_kinds() {
+    let error = from_toml::<Database>(
+        r#"
+        ports = [8000]
+        offset = 0
+        ratio = 0.5
+        enabled = 1979-05-27
+        "#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Boolean, found string \"1979-05-27\" at `/enabled`"
+    );
+
+    let error = from_toml::<Database>(
+        r#"
+        ports = [-1]
+        offset = 0
+        ratio = 0.5
+        enabled = true
+        "#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot deserialize -1 into a u16 at `/ports/0`"
+    );
+
+    let error = from_toml::<Database>(
+        r#"
+        ports = [1]
+        offset = 1.5
+        ratio = 0.5
+        enabled = true
+        "#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Integer or NegativeInteger, found float 1.5 at `/offset`"
+    );
+}
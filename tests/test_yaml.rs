@@ -0,0 +1,169 @@
+#![cfg(feature = "yaml")]
+
+use jayson::{DeserializeFromValue, StandardError, ValuePointer};
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(rename_all = camelCase, deny_unknown_fields)]
+struct Container {
+    name: String,
+    image: String,
+    replicas: u8,
+    resources: Resources,
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+#[jayson(deny_unknown_fields)]
+struct Resources {
+    cpu: f32,
+    memory: String,
+}
+
+#[track_caller]
+fn from_yaml<T: DeserializeFromValue<StandardError>>(s: &str) -> Result<T, StandardError> {
+    let value: serde_yaml::Value = serde_yaml::from_str(s).unwrap();
+    jayson::deserialize(value)
+}
+
+#[test]
+fn merge_keys() {
+    let containers = from_yaml::<Vec<Container>>(
+        r#"
+        - &base
+          name: web
+          image: nginx
+          replicas: 2
+          resources: &resources
+            cpu: 0.5
+            memory: 128Mi
+        - <<: *base
+          name: worker
+          resources:
+            <<: *resources
+            cpu: 2
+        "#,
+    )
+    .unwrap();
+    assert_eq!(containers[1].name, "worker");
+    assert_eq!(containers[1].image, "nginx");
+    assert_eq!(containers[1].replicas, 2);
+    assert_eq!(
+        containers[1].resources,
+        Resources {
+            cpu: 2.0,
+            memory: "128Mi".to_owned()
+        }
+    );
+}
+
+#[test]
+fn non_string_keys() {
+    let map = from_yaml::<BTreeMap<String, String>>(
+        r#"
+        1: a
+        -2: b
+        true: c
+        ~: d
+        [1, {x: y}]: e
+        "#,
+    )
+    .unwrap();
+    let keys = map.keys().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(keys, ["-2", "1", "[1, {x: y}]", "null", "true"]);
+
+    let map = from_yaml::<BTreeMap<u8, bool>>("{1: true, 2: false}").unwrap();
+    assert_eq!(map, [(1, true), (2, false)].into_iter().collect());
+}
+
+#[test]
+fn colliding_keys_are_rejected() {
+    let error = from_yaml::<BTreeMap<String, String>>("{1: a, '1': b}").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"1\" appears more than once in the map. at `/1`"
+    );
+
+    // also within nested values and merged mappings
+    let error = from_yaml::<Vec<BTreeMap<String, String>>>(
+        r#"
+        - &base { true: a }
+        - { <<: *base, "true": b }
+        "#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The key \"true\" appears more than once in the map. at `/1/true`"
+    );
+}
+
+#[test]
+fn merge_keys_keep_the_order_of_the_other_keys() {
+    let value: serde_yaml::Value =
+        serde_yaml::from_str("{ a: 1, <<: { z: 0 }, b: 2, c: 3 }").unwrap();
+    let value = jayson::OwnedValue::from_value::<_, StandardError>(value).unwrap();
+    let keys = match &value {
+        jayson::OwnedValue::Map(map) => map.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        _ => panic!("expected a map"),
+    };
+    assert_eq!(keys, ["a", "b", "c", "z"]);
+}
+
+#[derive(Debug, PartialEq, DeserializeFromValue)]
+struct Secret {
+    #[jayson(rename = "Base64")]
+    base64: String,
+}
+
+#[test]
+fn tagged_values() {
+    let secret = from_yaml::<Secret>("!Base64 aGVsbG8=").unwrap();
+    assert_eq!(secret.base64, "aGVsbG8=");
+
+    let error = from_yaml::<Resources>("!Base64 aGVsbG8=").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unknown key `Base64`: expected one of `cpu`, `memory`."
+    );
+}
+
+#[test]
+fn yaml_value_kinds() {
+    let error = from_yaml::<Resources>("{ cpu: ~, memory: 1Gi }").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected Float or Integer or NegativeInteger, found null at `/cpu`"
+    );
+}
+
+#[test]
+fn resolve_pointers() {
+    let value: serde_yaml::Value = serde_yaml::from_str(
+        r#"
+        - &base
+          name: web
+          1: one
+          resources: &resources
+            cpu: 0.5
+        - <<: *base
+          name: worker
+          resources:
+            <<: [*resources, { memory: 128Mi }]
+            cpu: 2
+        - !Base64 aGVsbG8=
+        "#,
+    )
+    .unwrap();
+    let resolve = |pointer: &str| {
+        let pointer = pointer.parse::<ValuePointer>().unwrap();
+        pointer.resolve(&value).cloned()
+    };
+    assert_eq!(resolve("$[1].name"), Some("worker".into()));
+    // keys are converted to strings, and merge keys are followed
+    assert_eq!(resolve("$[1][\"1\"]"), Some("one".into()));
+    assert_eq!(resolve("$[1].resources.cpu"), Some(2.into()));
+    assert_eq!(resolve("$[1].resources.memory"), Some("128Mi".into()));
+    assert_eq!(resolve("$[1][\"<<\"]"), None);
+    assert_eq!(resolve("$[2].Base64"), Some("aGVsbG8=".into()));
+    assert_eq!(resolve("$[2].Other"), None);
+}
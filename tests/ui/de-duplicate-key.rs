@@ -0,0 +1,18 @@
+use jayson::DeserializeFromValue;
+
+#[derive(DeserializeFromValue)]
+struct Struct {
+    first: u8,
+    #[jayson(alias = "first")]
+    second: u8,
+}
+
+#[derive(DeserializeFromValue)]
+#[jayson(tag = "t")]
+enum Enum {
+    A,
+    #[jayson(rename = "A")]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use jayson::DeserializeFromValue;
+
+#[derive(DeserializeFromValue)]
+#[serde(tag = "t", content = "c")]
+#[jayson(serde_compat)]
+enum Adjacent {
+    A { x: u8 },
+}
+
+#[derive(DeserializeFromValue)]
+#[jayson(serde_compat)]
+struct Flatten {
+    #[serde(flatten)]
+    inner: u8,
+}
+
+#[derive(DeserializeFromValue)]
+#[serde(untagged)]
+#[jayson(serde_compat)]
+enum Untagged {
+    A { x: u8 },
+}
+
+fn main() {}